@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+use crate::Error;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// Multi-line picture, such as the letters drawn by Day 10's CRT.
+    Grid(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::Text(x) => write!(f, "{}", x),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(x: i32) -> Self {
+        Answer::Int(x.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(x: i64) -> Self {
+        Answer::Int(x)
+    }
+}
+
+/// Counts and sizes too big for an `i64` can't be given as an answer.
+fn too_big<T: Display>(x: T) -> Error {
+    Error::NoSolution(format!("the answer {} does not fit in an i64", x))
+}
+
+impl TryFrom<u64> for Answer {
+    type Error = Error;
+
+    fn try_from(x: u64) -> Result<Self, Error> {
+        Ok(Answer::Int(x.try_into().map_err(|_| too_big(x))?))
+    }
+}
+
+impl TryFrom<usize> for Answer {
+    type Error = Error;
+
+    fn try_from(x: usize) -> Result<Self, Error> {
+        Ok(Answer::Int(x.try_into().map_err(|_| too_big(x))?))
+    }
+}
+
+impl TryFrom<isize> for Answer {
+    type Error = Error;
+
+    fn try_from(x: isize) -> Result<Self, Error> {
+        Ok(Answer::Int(x.try_into().map_err(|_| too_big(x))?))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(x: String) -> Self {
+        Answer::Text(x)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(x: &str) -> Self {
+        Answer::Text(x.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_big_for_i64_is_an_error() {
+        assert_eq!(Answer::try_from(7usize).unwrap(), Answer::Int(7));
+        assert_eq!(Answer::try_from(-7isize).unwrap(), Answer::Int(-7));
+        let max = i64::MAX as u64;
+        assert_eq!(Answer::try_from(max).unwrap(), Answer::Int(i64::MAX));
+        assert!(matches!(
+            Answer::try_from(max + 1),
+            Err(Error::NoSolution(_))
+        ));
+    }
+}
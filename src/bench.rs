@@ -0,0 +1,168 @@
+use std::{
+    fs,
+    io::{BufReader, Cursor, Read},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{registry::Solver, Answer, Config, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Stats need at least one sample.");
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct Measurement {
+    pub solver: &'static Solver,
+    pub runs: usize,
+    pub answer: Answer,
+    /// Reading the input file into memory.
+    pub read: Stats,
+    /// Parsing the input from memory.
+    pub parse: Stats,
+    /// Solving the already parsed input.
+    pub solve: Stats,
+}
+
+pub fn measure(
+    solver: &'static Solver,
+    input: &Path,
+    runs: usize,
+    config: &Config,
+) -> Result<Measurement, Error> {
+    let mut read = vec![];
+    let mut parse = vec![];
+    let mut solve = vec![];
+    let mut answer = None;
+
+    for _ in 0..runs.max(1) {
+        let t0 = Instant::now();
+        let bytes = fs::read(input)?;
+        read.push(t0.elapsed());
+
+        let t0 = Instant::now();
+        let reader: Box<dyn Read> = Box::new(Cursor::new(bytes));
+        let parsed = solver.parse(BufReader::new(reader))?;
+        parse.push(t0.elapsed());
+
+        let t0 = Instant::now();
+        answer = Some(solver.solve_parsed(parsed.as_ref(), config)?);
+        solve.push(t0.elapsed());
+    }
+
+    Ok(Measurement {
+        solver,
+        runs: read.len(),
+        answer: answer.unwrap(),
+        read: Stats::from_samples(read),
+        parse: Stats::from_samples(parse),
+        solve: Stats::from_samples(solve),
+    })
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!("{:?} / {:?} / {:?}", stats.min, stats.median, stats.max)
+}
+
+pub fn print_text(measurements: &[Measurement]) {
+    println!(
+        "{:>4} {:>3} {:>4}  {:<26} {:>34}  {:>34}  {:>34}",
+        "year",
+        "day",
+        "part",
+        "name",
+        "read (min / median / max)",
+        "parse (min / median / max)",
+        "solve (min / median / max)"
+    );
+    for m in measurements {
+        println!(
+            "{:>4} {:>3} {:>4}  {:<26} {:>34}  {:>34}  {:>34}",
+            m.solver.year,
+            m.solver.day,
+            m.solver.part,
+            m.solver.name,
+            format_stats(&m.read),
+            format_stats(&m.parse),
+            format_stats(&m.solve)
+        );
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        "{{\"min\": {}, \"median\": {}, \"max\": {}}}",
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.max.as_nanos()
+    )
+}
+
+/// One JSON object per solver, durations in nanoseconds.
+pub fn to_json(measurements: &[Measurement]) -> String {
+    let rows = measurements
+        .iter()
+        .map(|m| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"name\": {}, \"runs\": {}, \"answer\": {}, \"read_ns\": {}, \"parse_ns\": {}, \"solve_ns\": {}}}",
+                m.solver.year,
+                m.solver.day,
+                m.solver.part,
+                json_string(m.solver.name),
+                m.runs,
+                json_string(&m.answer.to_string()),
+                json_stats(&m.read),
+                json_stats(&m.parse),
+                json_stats(&m.solve)
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[\n{}\n]", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{registry, Answer, Config};
+
+    #[test]
+    fn bench_stats() {
+        let ms = |x| std::time::Duration::from_millis(x);
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(9), ms(2), ms(3)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(9)));
+
+        let solver = registry::find(2022, 6, 1).unwrap();
+        let m = measure(solver, &solver.input(), 3, &Config::new()).unwrap();
+        assert_eq!((m.runs, &m.answer), (3, &Answer::Int(1142)));
+        assert!(to_json(&[m]).contains("\"name\": \"Tuning Trouble\""));
+    }
+}
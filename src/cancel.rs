@@ -0,0 +1,132 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::Error;
+
+/// Tells a long running solver to give up, either when asked to or once a deadline passes.
+/// Solvers check it now and then and return `Error::TimedOut`. The default token never fires.
+#[derive(Debug, Clone, Default)]
+pub struct Cancel {
+    flag: Option<Arc<AtomicBool>>,
+    deadline: Option<Instant>,
+}
+
+impl Cancel {
+    pub fn new() -> Self {
+        Self {
+            flag: Some(Arc::new(AtomicBool::new(false))),
+            deadline: None,
+        }
+    }
+
+    /// Fires `timeout` from now.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        self
+    }
+
+    /// Fires the token and its clones.
+    pub fn cancel(&self) {
+        if let Some(flag) = &self.flag {
+            flag.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag
+            .as_ref()
+            .is_some_and(|f| f.load(Ordering::Relaxed))
+            || self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    pub fn check(&self) -> Result<(), Error> {
+        match self.is_cancelled() {
+            true => Err(Error::TimedOut),
+            false => Ok(()),
+        }
+    }
+
+    /// A cheaper check for hot loops like `Exploration` callbacks.
+    pub fn ticker(&self) -> Ticker<'_> {
+        Ticker {
+            cancel: self,
+            count: 0,
+            cancelled: false,
+        }
+    }
+}
+
+impl PartialEq for Cancel {
+    /// Tokens are equal when they are clones of each other.
+    fn eq(&self, other: &Self) -> bool {
+        let same_flag = match (&self.flag, &other.flag) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        same_flag && self.deadline == other.deadline
+    }
+}
+
+impl Eq for Cancel {}
+
+/// Looks at the token only every few hundred calls, and keeps saying so once it fired.
+pub struct Ticker<'a> {
+    cancel: &'a Cancel,
+    count: u32,
+    cancelled: bool,
+}
+
+impl Ticker<'_> {
+    const CHECK_EVERY: u32 = 256;
+
+    pub fn tick(&mut self) -> bool {
+        if !self.cancelled {
+            self.count += 1;
+            if self.count == Self::CHECK_EVERY {
+                self.count = 0;
+                self.cancelled = self.cancel.is_cancelled();
+            }
+        }
+        self.cancelled
+    }
+
+    pub fn check(&self) -> Result<(), Error> {
+        match self.cancelled {
+            true => Err(Error::TimedOut),
+            false => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{inputs, Config, Day, Problem};
+
+    #[test]
+    fn cancel_gives_up() {
+        let cancel = Cancel::new();
+        assert!(cancel.check().is_ok());
+        cancel.clone().cancel();
+        assert!(matches!(cancel.check(), Err(Error::TimedOut)));
+        assert_ne!(cancel, Cancel::new());
+        assert_eq!(Cancel::default(), Cancel::default());
+
+        let expired = Cancel::new().with_timeout(std::time::Duration::ZERO);
+        let mut ticker = expired.ticker();
+        assert!((0..1000).any(|_| ticker.tick()));
+        assert!(ticker.check().is_err());
+
+        let config = Config::new().with_cancel(expired);
+        let input = Day::<15>::parse_file(inputs::path(2022, 15, None)).unwrap();
+        assert!(matches!(
+            Day::<15>::part2(&input, &config),
+            Err(Error::TimedOut)
+        ));
+    }
+}
@@ -0,0 +1,497 @@
+use std::{str::FromStr, time::Duration};
+
+use aoc2022::{
+    trace::{Filter, Level},
+    Config,
+};
+
+pub const USAGE: &str = "\
+Usage: aoc2022 <command> [options]
+
+Commands:
+    run     Solve one puzzle part and print the answer
+    run-all Solve every puzzle part in parallel and print a summary
+    bench   Time solvers over repeated runs
+    verify  Check solvers against the known answers
+    new-day Start a new puzzle from src/day_template.rs
+    generate
+            Write a random input for days 4, 12, 15, 16, 17, 19, 22 and 24
+    compare Run alternative solvers of days 4, 12 and 17 on random inputs and show the
+            smallest input found where they disagree
+
+Options for run:
+    -y, --year <YEAR>   Event year (default the latest)
+    -d, --day <N>       Puzzle day (1-25)
+    -p, --part <N>      Puzzle part (1 or 2, default 1)
+    -i, --input <PATH>  Input file, or - for stdin (default inputs/<year>/in<day>.txt)
+    -e, --example [NAME]
+                        Use the example input inputs/<year>/in<day>_<NAME>.txt (default small)
+    -s, --set <K=V>     Set a puzzle parameter, e.g. row=10 for the day 15 example
+    -t, --timeout <SECS>
+                        Give up after this many seconds
+    -r, --record <PATH> Record the grid of days 14, 17, 22, 23 and 24 step by step, as one
+                        text file or, for a path ending in '/', numbered frames
+    --image <PATH>      Draw days 8, 10, 12, 14, 18, 23 and 24 to a PNG, or PPM for .ppm
+    --dot <PATH>        Write the graph of days 7, 12, 16 and 21 in Graphviz DOT
+
+Options for run-all:
+    -y, --year <YEAR>   Only this year (default all)
+    -j, --jobs <N>      Solvers running at once (default one per CPU)
+    -t, --timeout <SECS>
+                        Give up on a solver after this many seconds
+    -s, --set <K=V>     Set a puzzle parameter
+
+Options for bench:
+    -y, --year <YEAR>   Only this year (default all)
+    -d, --day <N>       Only this day (default all)
+    -p, --part <N>      Only this part (default both)
+    -n, --runs <N>      Runs per solver (default 5)
+    -f, --format <FMT>  Output format, text or json (default text)
+    -s, --set <K=V>     Set a puzzle parameter
+
+Options for verify:
+    -y, --year <YEAR>     Only this year (default all)
+    -d, --day <N>         Only this day (default all)
+    -p, --part <N>        Only this part (default both)
+    -a, --answers <PATH>  Answers file (default answers.txt)
+
+Options for new-day:
+    -y, --year <YEAR>   Event year, new ones get src/y<YEAR> (default the latest)
+    -d, --day <N>       Puzzle day (1-25)
+    -n, --name <NAME>   Puzzle title (default Day <N>)
+
+Options for generate:
+    -y, --year <YEAR>   Event year (default the latest)
+    -d, --day <N>       Puzzle day
+    --seed <N>          Random seed, the same one gives the same input (default random)
+    --size <N>          How big, e.g. the number of sensors for day 15 or the width of a
+                        cube face for day 22 (default about the real input's)
+    -o, --output <PATH> Write here instead of stdout
+
+Options for compare:
+    -y, --year <YEAR>   Only this year (default all)
+    -d, --day <N>       Only this day (default all)
+    -p, --part <N>      Only this part (default both)
+    --seed <N>          Seed of the first input, the rest count up from it (default 0)
+    -n, --runs <N>      Inputs per pair of solvers (default 100)
+    --size <N>          Input size like for generate (default about the real input's)
+
+The inputs directory can be changed with the AOC_INPUTS environment variable.
+
+Logging, for any command (written to stderr, off by default):
+    -v, -vv, -vvv       Log info, debug or trace messages from every day
+    --log <FILTER>      Log by level and day, e.g. debug or info,day22=trace
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        /// The latest year when left out.
+        year: Option<u32>,
+        day: u32,
+        part: u32,
+        input: Option<String>,
+        /// Name of an example input, `small` for `in<day>_small.txt`.
+        example: Option<String>,
+        timeout: Option<Duration>,
+        config: Config,
+    },
+    RunAll {
+        year: Option<u32>,
+        jobs: Option<usize>,
+        timeout: Option<Duration>,
+        config: Config,
+    },
+    Bench {
+        year: Option<u32>,
+        day: Option<u32>,
+        part: Option<u32>,
+        runs: usize,
+        format: Format,
+        config: Config,
+    },
+    Verify {
+        year: Option<u32>,
+        day: Option<u32>,
+        part: Option<u32>,
+        answers: Option<String>,
+    },
+    NewDay {
+        year: Option<u32>,
+        day: u32,
+        name: Option<String>,
+    },
+    Generate {
+        year: Option<u32>,
+        day: u32,
+        /// A random one, printed so it can be reused, when left out.
+        seed: Option<u64>,
+        size: Option<usize>,
+        output: Option<String>,
+    },
+    Compare {
+        year: Option<u32>,
+        day: Option<u32>,
+        part: Option<u32>,
+        seed: u64,
+        runs: u64,
+        size: Option<usize>,
+    },
+    Help,
+}
+
+fn value<I>(args: &mut I, flag: &str) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    args.next()
+        .ok_or_else(|| format!("missing value for '{}'", flag))
+}
+
+fn number<I, N>(args: &mut I, flag: &str) -> Result<N, String>
+where
+    I: Iterator<Item = String>,
+    N: FromStr,
+{
+    let v = value(args, flag)?;
+    v.parse()
+        .map_err(|_| format!("invalid number '{}' for '{}'", v, flag))
+}
+
+fn seconds<I>(args: &mut I, flag: &str) -> Result<Duration, String>
+where
+    I: Iterator<Item = String>,
+{
+    let v = value(args, flag)?;
+    v.parse()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid number of seconds '{}' for '{}'", v, flag))
+}
+
+fn assignment<I>(args: &mut I, flag: &str, config: &mut Config) -> Result<(), String>
+where
+    I: Iterator<Item = String>,
+{
+    let v = value(args, flag)?;
+    config.assign(&v).map_err(|_| {
+        format!(
+            "invalid parameter '{}' for '{}', expected KEY=VALUE",
+            v, flag
+        )
+    })
+}
+
+/// Takes the logging options out of `args`, wherever they appear, returning the filter they
+/// describe and the remaining arguments.
+pub fn split_log<I>(mut args: I) -> Result<(Filter, Vec<String>), String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut filter = Filter::new();
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "-vv" | "-vvv" => {
+                let level = Level::from_verbosity(arg.len() - 1);
+                filter = filter.merge(Filter::new().with_level(level));
+            }
+            "--log" => {
+                let v = value(&mut args, &arg)?;
+                let directives = Filter::parse(&v).map_err(|e| {
+                    format!(
+                        "invalid log filter '{}': expected {} at '{}'",
+                        v, e.what, e.at
+                    )
+                })?;
+                filter = filter.merge(directives);
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    Ok((filter, rest))
+}
+
+fn check_part(part: u32) -> Result<u32, String> {
+    match part {
+        1 | 2 => Ok(part),
+        _ => Err(format!("part must be 1 or 2, got {}", part)),
+    }
+}
+
+impl Command {
+    pub fn parse<I>(mut args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        match args.next().as_deref() {
+            Some("run") => Self::parse_run(args),
+            Some("run-all") => Self::parse_run_all(args),
+            Some("bench") => Self::parse_bench(args),
+            Some("verify") => Self::parse_verify(args),
+            Some("new-day") => Self::parse_new_day(args),
+            Some("generate") => Self::parse_generate(args),
+            Some("compare") => Self::parse_compare(args),
+            Some("help" | "-h" | "--help") | None => Ok(Command::Help),
+            Some(other) => Err(format!("unknown command '{}'", other)),
+        }
+    }
+
+    fn parse_run<I>(args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut args = args.peekable();
+        let mut year = None;
+        let mut day = None;
+        let mut part = 1;
+        let mut input = None;
+        let mut example = None;
+        let mut timeout = None;
+        let mut config = Config::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(number(&mut args, &arg)?),
+                "-d" | "--day" => day = Some(number(&mut args, &arg)?),
+                "-p" | "--part" => part = number(&mut args, &arg)?,
+                "-i" | "--input" => input = Some(value(&mut args, &arg)?),
+                "-e" | "--example" => {
+                    let name = args.next_if(|v| !v.starts_with('-'));
+                    example = Some(name.unwrap_or_else(|| "small".into()));
+                }
+                "-t" | "--timeout" => timeout = Some(seconds(&mut args, &arg)?),
+                "-s" | "--set" => assignment(&mut args, &arg, &mut config)?,
+                "-r" | "--record" => config = config.with("record", value(&mut args, &arg)?),
+                "--image" => config = config.with("image", value(&mut args, &arg)?),
+                "--dot" => config = config.with("dot", value(&mut args, &arg)?),
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        let day = day.ok_or("missing required option '--day'")?;
+        check_part(part)?;
+        if input.is_some() && example.is_some() {
+            return Err("'--input' and '--example' can't be used together".into());
+        }
+
+        Ok(Command::Run {
+            year,
+            day,
+            part,
+            input,
+            example,
+            timeout,
+            config,
+        })
+    }
+
+    fn parse_run_all<I>(mut args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut year = None;
+        let mut jobs = None;
+        let mut timeout = None;
+        let mut config = Config::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(number(&mut args, &arg)?),
+                "-j" | "--jobs" => jobs = Some(number(&mut args, &arg)?),
+                "-t" | "--timeout" => timeout = Some(seconds(&mut args, &arg)?),
+                "-s" | "--set" => assignment(&mut args, &arg, &mut config)?,
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        if jobs == Some(0) {
+            return Err("jobs must be at least 1".into());
+        }
+
+        Ok(Command::RunAll {
+            year,
+            jobs,
+            timeout,
+            config,
+        })
+    }
+
+    fn parse_bench<I>(mut args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut year = None;
+        let mut day = None;
+        let mut part = None;
+        let mut runs = 5;
+        let mut format = Format::Text;
+        let mut config = Config::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(number(&mut args, &arg)?),
+                "-d" | "--day" => day = Some(number(&mut args, &arg)?),
+                "-p" | "--part" => part = Some(check_part(number(&mut args, &arg)?)?),
+                "-n" | "--runs" => runs = number(&mut args, &arg)?,
+                "-f" | "--format" => {
+                    format = match value(&mut args, &arg)?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        other => return Err(format!("unknown format '{}'", other)),
+                    }
+                }
+                "-s" | "--set" => assignment(&mut args, &arg, &mut config)?,
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        if runs == 0 {
+            return Err("runs must be at least 1".into());
+        }
+
+        Ok(Command::Bench {
+            year,
+            day,
+            part,
+            runs,
+            format,
+            config,
+        })
+    }
+
+    fn parse_verify<I>(mut args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut year = None;
+        let mut day = None;
+        let mut part = None;
+        let mut answers = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(number(&mut args, &arg)?),
+                "-d" | "--day" => day = Some(number(&mut args, &arg)?),
+                "-p" | "--part" => part = Some(check_part(number(&mut args, &arg)?)?),
+                "-a" | "--answers" => answers = Some(value(&mut args, &arg)?),
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        Ok(Command::Verify {
+            year,
+            day,
+            part,
+            answers,
+        })
+    }
+
+    fn parse_new_day<I>(mut args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut year = None;
+        let mut day = None;
+        let mut name = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(number(&mut args, &arg)?),
+                "-d" | "--day" => day = Some(number(&mut args, &arg)?),
+                "-n" | "--name" => name = Some(value(&mut args, &arg)?),
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        let day = day.ok_or("missing required option '--day'")?;
+        if !(1..=25).contains(&day) {
+            return Err(format!("day must be between 1 and 25, got {}", day));
+        }
+
+        Ok(Command::NewDay { year, day, name })
+    }
+
+    fn parse_generate<I>(mut args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut year = None;
+        let mut day = None;
+        let mut seed = None;
+        let mut size = None;
+        let mut output = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(number(&mut args, &arg)?),
+                "-d" | "--day" => day = Some(number(&mut args, &arg)?),
+                "--seed" => seed = Some(number(&mut args, &arg)?),
+                "--size" => size = Some(number(&mut args, &arg)?),
+                "-o" | "--output" => output = Some(value(&mut args, &arg)?),
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        let day = day.ok_or("missing required option '--day'")?;
+        if size == Some(0) {
+            return Err("size must be at least 1".into());
+        }
+
+        Ok(Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+            output,
+        })
+    }
+
+    fn parse_compare<I>(mut args: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut year = None;
+        let mut day = None;
+        let mut part = None;
+        let mut seed = 0;
+        let mut runs = 100;
+        let mut size = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(number(&mut args, &arg)?),
+                "-d" | "--day" => day = Some(number(&mut args, &arg)?),
+                "-p" | "--part" => part = Some(check_part(number(&mut args, &arg)?)?),
+                "--seed" => seed = number(&mut args, &arg)?,
+                "-n" | "--runs" => runs = number(&mut args, &arg)?,
+                "--size" => size = Some(number(&mut args, &arg)?),
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        if runs == 0 {
+            return Err("runs must be at least 1".into());
+        }
+        if size == Some(0) {
+            return Err("size must be at least 1".into());
+        }
+
+        Ok(Command::Compare {
+            year,
+            day,
+            part,
+            seed,
+            runs,
+            size,
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use crate::{cancel::Cancel, error::Expected, Error};
+
+/// Named puzzle parameters, such as the row day 15 looks at, given as text on the command line
+/// or in the answers file. Each part reads the ones it knows about and falls back to the values
+/// from the puzzle text. Also carries the token telling slow solvers to give up.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    params: BTreeMap<String, String>,
+    cancel: Cancel,
+    /// Keys asked for so far, shared by clones so a run on other threads counts too.
+    used: Arc<Mutex<BTreeSet<String>>>,
+}
+
+impl PartialEq for Config {
+    fn eq(&self, other: &Self) -> bool {
+        self.params == other.params && self.cancel == other.cancel
+    }
+}
+
+impl Eq for Config {}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_cancel(mut self, cancel: Cancel) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn cancel(&self) -> &Cancel {
+        &self.cancel
+    }
+
+    /// Reads a `key=value` assignment.
+    pub fn assign<'a>(&mut self, assignment: &'a str) -> Result<(), Expected<'a>> {
+        match assignment.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                self.params
+                    .insert(key.trim().to_string(), value.trim().to_string());
+                Ok(())
+            }
+            _ => Err(Expected::new(assignment, "a parameter like 'row=10'")),
+        }
+    }
+
+    /// Reads comma-separated `key=value` assignments, the format `Display` writes.
+    pub fn parse(s: &str) -> Result<Self, Expected<'_>> {
+        let mut config = Self::new();
+        for assignment in s.split(',').filter(|x| !x.trim().is_empty()) {
+            config.assign(assignment)?;
+        }
+        Ok(config)
+    }
+
+    /// Parameters given that no solver asked for, likely misspelled.
+    pub fn unused(&self) -> Vec<&str> {
+        let used = self.used.lock().unwrap();
+        self.params
+            .keys()
+            .filter(|key| !used.contains(*key))
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// The parameter `key`, or `default` if it was not given.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T, Error> {
+        self.used.lock().unwrap().insert(key.to_string());
+        match self.params.get(key) {
+            Some(value) => value.parse().map_err(|_| Error::Parameter {
+                key: key.to_string(),
+                value: value.clone(),
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+impl Display for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = self
+            .params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>();
+        write!(f, "{}", params.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_parameters() {
+        let config = Config::parse("row=10, size=21").unwrap();
+        assert_eq!(config, Config::new().with("row", "10").with("size", "21"));
+        assert_eq!(config.to_string(), "row=10,size=21");
+        assert_eq!(config.get("row", 2000000).unwrap(), 10);
+        assert_eq!(config.get("face", 50).unwrap(), 50);
+        assert_eq!(config.unused(), ["size"]);
+        assert!(Config::parse("row").is_err());
+
+        let config = Config::new().with("row", "ten");
+        assert!(matches!(
+            config.get("row", 0),
+            Err(Error::Parameter { key, value }) if key == "row" && value == "ten"
+        ));
+    }
+}
@@ -1,23 +1,24 @@
 use std::{io::prelude::*, io::BufReader};
 
-use crate::{Day, Problem};
+use crate::{Answer, Config, Day, Error, Problem};
 
 impl Problem for Day<0> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+    type Input = Vec<String>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        let mut result = 0;
-
-        // let lines = reader.lines().map(|x| x.unwrap()).collect::<Vec<_>>();
+        Ok(reader.lines().collect::<Result<Vec<_>, _>>()?)
+    }
 
-        for line in reader.lines().map(|x| x.unwrap()) {
-            writeln!(writer, "{}", line.len()).unwrap();
+    fn part1(input: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let mut result = 0;
 
+        for line in input.iter() {
             result = std::cmp::max(result, line.len());
         }
 
-        writeln!(writer, "{}", result).unwrap();
+        Answer::try_from(result)
     }
 }
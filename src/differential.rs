@@ -0,0 +1,194 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{
+    generate::{self, Generator},
+    inputs::Text,
+    runner::panic_message,
+    Answer, Config, Error, Problem,
+};
+
+/// What one side made of an input: the answer, or the error or panic message.
+pub type Outcome = Result<Answer, String>;
+
+type SolveFn = fn(&str, &Config) -> Result<Answer, Error>;
+
+fn solve_text<P: Problem, const N: u32>(text: &str, config: &Config) -> Result<Answer, Error> {
+    let input = P::parse(Text::new(text).into_reader())?;
+    match N {
+        1 => P::part1(&input, config),
+        _ => P::part2(&input, config),
+    }
+}
+
+/// Two implementations of one puzzle part that should agree on every input.
+pub struct Pair {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub names: [&'static str; 2],
+    solve: [SolveFn; 2],
+}
+
+/// An input the two sides of a pair disagree on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// Seed of the generated input the disagreement was found on.
+    pub seed: u64,
+    /// The smallest input found that the sides still disagree on.
+    pub input: String,
+    pub outcomes: [Outcome; 2],
+}
+
+impl Pair {
+    pub(crate) const fn new<L: Problem, R: Problem>(
+        year: u32,
+        day: u32,
+        part: u32,
+        names: [&'static str; 2],
+    ) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            names,
+            solve: if part == 1 {
+                [solve_text::<L, 1>, solve_text::<R, 1>]
+            } else {
+                [solve_text::<L, 2>, solve_text::<R, 2>]
+            },
+        }
+    }
+
+    /// The generator the pair is checked with.
+    pub fn generator(&self) -> Option<&'static Generator> {
+        generate::find(self.year, self.day)
+    }
+
+    /// Solves `text` with both sides. A panic on either side is an outcome like any other.
+    pub fn run(&self, text: &str, config: &Config) -> [Outcome; 2] {
+        self.solve.map(|solve| {
+            match panic::catch_unwind(AssertUnwindSafe(|| solve(text, config))) {
+                Ok(Ok(answer)) => Ok(answer),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(payload) => Err(format!("panicked: {}", panic_message(payload))),
+            }
+        })
+    }
+
+    /// Runs both sides on `runs` generated inputs with consecutive seeds from `seed`, stopping at
+    /// the first one they disagree on. That input is minimized before it is returned.
+    pub fn check(
+        &self,
+        seed: u64,
+        runs: u64,
+        size: Option<usize>,
+        config: &Config,
+    ) -> Option<Divergence> {
+        let generator = self.generator()?;
+        let diverges = |text: &str| {
+            let [left, right] = self.run(text, config);
+            left != right
+        };
+
+        let seed = (seed..seed.saturating_add(runs))
+            .find(|&seed| diverges(&generator.generate(seed, size)))?;
+        let input = minimize(&generator.generate(seed, size), diverges);
+        Some(Divergence {
+            seed,
+            outcomes: self.run(&input, config),
+            input,
+        })
+    }
+}
+
+/// Takes out ever smaller runs of items while `keep` still holds for the rest.
+fn shrink<T: Clone>(items: &mut Vec<T>, mut keep: impl FnMut(&[T]) -> bool) {
+    let mut chunk = items.len();
+    while chunk > 0 {
+        let mut start = 0;
+        while start < items.len() {
+            let mut candidate = items.clone();
+            candidate.drain(start..(start + chunk).min(items.len()));
+            match keep(&candidate) {
+                true => *items = candidate,
+                false => start += chunk,
+            }
+        }
+        chunk /= 2;
+    }
+}
+
+/// Removes lines, then characters within the lines that are left, as long as `keep` still holds
+/// for what remains. Cheap rather than minimal, but good at getting a long input down to the part
+/// that matters.
+pub fn minimize(text: &str, mut keep: impl FnMut(&str) -> bool) -> String {
+    let join =
+        |lines: &[String]| -> String { lines.iter().map(|line| format!("{}\n", line)).collect() };
+
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+    shrink(&mut lines, |lines| keep(&join(lines)));
+    for index in 0..lines.len() {
+        let mut chars = lines[index].chars().collect::<Vec<_>>();
+        shrink(&mut chars, |chars| {
+            let mut candidate = lines.clone();
+            candidate[index] = chars.iter().collect();
+            keep(&join(&candidate))
+        });
+        lines[index] = chars.into_iter().collect();
+    }
+    join(&lines)
+}
+
+#[rustfmt::skip]
+const YEARS: &[&[Pair]] = &[
+    crate::y2022::PAIRS,
+];
+
+pub fn pairs() -> impl Iterator<Item = &'static Pair> {
+    YEARS.iter().flat_map(|pairs| pairs.iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer::Int;
+    use std::io::BufReader;
+
+    #[test]
+    fn differential_pairs() {
+        /// Counts lines, or with `X` only those without an 'x' like a buggy rewrite would.
+        struct Lines<const X: bool>;
+        impl<const X: bool> Problem for Lines<X> {
+            type Input = Vec<String>;
+
+            fn parse<T: std::io::Read>(reader: BufReader<T>) -> Result<Self::Input, Error> {
+                Ok(std::io::BufRead::lines(reader).collect::<Result<_, _>>()?)
+            }
+
+            fn part1(lines: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+                Answer::try_from(
+                    lines
+                        .iter()
+                        .filter(|line| !X || !line.contains('x'))
+                        .count(),
+                )
+            }
+        }
+
+        let pair = Pair::new::<Lines<false>, Lines<true>>(2022, 0, 1, ["all", "without x"]);
+        let text = "ab\ncxd\nef\nxx\n";
+        assert_eq!(pair.run(text, &Config::new()), [Ok(Int(4)), Ok(Int(2))]);
+        let diverges = |text: &str| {
+            let [all, without_x] = pair.run(text, &Config::new());
+            all != without_x
+        };
+        assert_eq!(minimize(text, diverges), "x\n");
+        // Day 0 has no generator to check with.
+        assert_eq!(pair.check(0, 10, None, &Config::new()), None);
+
+        for pair in pairs() {
+            assert!(pair.generator().is_some());
+            assert_eq!(pair.check(0, 10, Some(30), &Config::new()), None);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fmt::Display, fs, path::Path};
+
+use crate::{Config, Error};
+
+/// A graph in Graphviz DOT, built a node and an edge at a time. `dot -Tsvg out.dot -o out.svg`
+/// draws it, or `neato` for graphs whose nodes carry a `pos`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    directed: bool,
+    statements: Vec<String>,
+}
+
+/// A DOT string literal, so any name or label can be used as an id.
+pub fn quote<T: Display>(text: T) -> String {
+    let text = text.to_string();
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+impl Graph {
+    /// Edges have a direction, like from a directory to what is in it.
+    pub fn directed() -> Self {
+        Self {
+            directed: true,
+            statements: vec![],
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Self {
+            directed: false,
+            statements: vec![],
+        }
+    }
+
+    /// Sets an attribute of the whole graph, like `rankdir=LR`.
+    pub fn with(mut self, attribute: &str) -> Self {
+        self.statements.push(format!("{};", attribute));
+        self
+    }
+
+    pub fn node<T: Display>(&mut self, id: T, label: &str) {
+        self.node_with(id, label, "");
+    }
+
+    /// A node with more attributes in DOT syntax, like `shape=box`.
+    pub fn node_with<T: Display>(&mut self, id: T, label: &str, attributes: &str) {
+        self.statements.push(statement(
+            quote(id),
+            &format!("label={}", quote(label)),
+            attributes,
+        ));
+    }
+
+    pub fn edge<T: Display>(&mut self, from: T, to: T) {
+        self.edge_with(from, to, "");
+    }
+
+    /// An edge with attributes in DOT syntax, like `label="3"`.
+    pub fn edge_with<T: Display>(&mut self, from: T, to: T, attributes: &str) {
+        let arrow = match self.directed {
+            true => "->",
+            false => "--",
+        };
+        let edge = format!("{} {} {}", quote(from), arrow, quote(to));
+        self.statements.push(statement(edge, "", attributes));
+    }
+
+    pub fn to_dot(&self) -> String {
+        let kind = match self.directed {
+            true => "digraph",
+            false => "graph",
+        };
+        let mut dot = format!("{} {{\n", kind);
+        for statement in &self.statements {
+            dot += &format!("    {}\n", statement);
+        }
+        dot += "}\n";
+        dot
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        fs::write(path, self.to_dot())?;
+        Ok(())
+    }
+}
+
+fn statement(subject: String, first: &str, second: &str) -> String {
+    let attributes = [first, second]
+        .into_iter()
+        .filter(|a| !a.is_empty())
+        .collect::<Vec<_>>();
+    match attributes.is_empty() {
+        true => format!("{};", subject),
+        false => format!("{} [{}];", subject, attributes.join(", ")),
+    }
+}
+
+/// Saves the graph `draw` builds to the path in the `dot` parameter, if there is one.
+pub fn export<F: FnOnce() -> Graph>(config: &Config, draw: F) -> Result<(), Error> {
+    let path: String = config.get("dot", String::new())?;
+    if !path.is_empty() {
+        draw().save(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{inputs, Answer::Int, Day, Problem};
+
+    #[test]
+    fn dot_graphs() {
+        assert_eq!(quote("say \"hi\"\nnow"), r#""say \"hi\"\nnow""#);
+
+        let mut graph = Graph::undirected().with("rankdir=LR");
+        graph.node("AA", "AA\nrate 0");
+        graph.node_with("BB", "BB\nrate 13", "style=bold");
+        graph.edge_with("AA", "BB", "label=\"1\"");
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n    rankdir=LR;\n    \"AA\" [label=\"AA\\nrate 0\"];\n    \"BB\" \
+             [label=\"BB\\nrate 13\", style=bold];\n    \"AA\" -- \"BB\" [label=\"1\"];\n}\n"
+        );
+
+        let path = std::env::temp_dir().join("aoc2022_dot_graphs.dot");
+        let config = Config::new().with("dot", path.to_str().unwrap());
+        let tree = Day::<7>::parse_file(inputs::dir().join("2022/in7_small.txt")).unwrap();
+        assert_eq!(Day::<7>::part1(&tree, &config).unwrap(), Int(95437));
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            text.starts_with("digraph {\n    rankdir=LR;\n    \"/\" [label=\"/\\n48381165\"];\n")
+        );
+        assert!(text.contains("    \"/a/e/i\" [label=\"i\\n584\", shape=box];\n"));
+        assert!(text.contains("    \"/a\" -> \"/a/e\";\n"));
+    }
+}
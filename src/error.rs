@@ -0,0 +1,202 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Location in the puzzle input that could not be parsed and what was expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number, or 0 if the error concerns the input as a whole.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The offending line.
+    pub text: String,
+}
+
+impl ParseError {
+    /// Points at `fragment`, which must be a slice of `text`, the `line`th line of the input.
+    pub fn at<S: Into<String>>(line: usize, text: &str, fragment: &str, expected: S) -> Self {
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+        let column = match text.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        };
+
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            text: text.to_string(),
+        }
+    }
+
+    /// Something that should appear somewhere in the input but does not.
+    pub fn missing<S: Into<String>>(expected: S) -> Self {
+        Self {
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            text: String::new(),
+        }
+    }
+
+    /// Renders the error with the offending line and a caret under the column.
+    pub fn diagnostic(&self, source: &str) -> String {
+        if self.line == 0 {
+            return format!("error: expected {}\n --> {}\n", self.expected, source);
+        }
+
+        let gutter = self.line.to_string().len();
+        format!(
+            "error: expected {expected}\n{:gutter$}--> {source}:{line}:{column}\n\
+             {:gutter$} |\n\
+             {line} | {text}\n\
+             {:gutter$} | {:>column$}\n",
+            "",
+            "",
+            "",
+            "^",
+            expected = self.expected,
+            source = source,
+            line = self.line,
+            column = self.column,
+            text = self.text,
+            gutter = gutter,
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            0 => write!(f, "expected {}", self.expected),
+            _ => write!(
+                f,
+                "line {}, column {}: expected {}",
+                self.line, self.column, self.expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse failure reported by helpers that only see part of a line.
+/// The caller knows which line it was and turns it into a `ParseError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expected<'a> {
+    pub at: &'a str,
+    pub what: &'static str,
+}
+
+impl<'a> Expected<'a> {
+    pub fn new(at: &'a str, what: &'static str) -> Self {
+        Self { at, what }
+    }
+
+    pub fn on_line(self, line: usize, text: &str) -> ParseError {
+        ParseError::at(line, text, self.at, self.what)
+    }
+}
+
+/// Parses the trimmed `fragment` of `text`, the `line`th line of the input.
+pub fn parse_field<F: FromStr>(
+    line: usize,
+    text: &str,
+    fragment: &str,
+    expected: &str,
+) -> Result<F, ParseError> {
+    let fragment = fragment.trim();
+    fragment
+        .parse()
+        .map_err(|_| ParseError::at(line, text, fragment, expected))
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Parse(ParseError),
+    /// The solver does not cover this part of the puzzle.
+    NotImplemented,
+    /// A puzzle parameter that does not parse as what the solver expects.
+    Parameter {
+        key: String,
+        value: String,
+    },
+    /// The solver gave up when its time ran out, see `Cancel`.
+    TimedOut,
+    /// The input parsed, but has no answer, like a valley with no way through it.
+    NoSolution(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::NotImplemented => write!(f, "not implemented"),
+            Error::Parameter { key, value } => {
+                write!(f, "invalid value '{}' for parameter '{}'", value, key)
+            }
+            Error::TimedOut => write!(f, "timed out"),
+            Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::parse_error, Config, Day, Problem};
+    use std::io::BufReader;
+
+    #[test]
+    fn parse_errors_point_at_the_problem() {
+        let e = parse_error::<Day<1>>("100\n\n2x0\n");
+        assert_eq!((e.line, e.column), (3, 1));
+
+        let e = parse_error::<Day<10>>("noop\naddx -x\n");
+        assert_eq!((e.line, e.column, e.expected.as_str()), (2, 6, "a number"));
+
+        let e = parse_error::<Day<13>>("[1,[2,3]]\n[1,[2,3]]]\n");
+        assert_eq!((e.line, e.column), (2, 10));
+
+        let e = parse_error::<Day<15>>("Sensor at x=2, y=18: closest beacon is at x=-2, y=1S\n");
+        assert_eq!((e.line, e.column), (1, 51));
+
+        let e = parse_error::<Day<21>>("root: pppw + sjmn\nsjmn 4\n");
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_error::<Day<21>>("root: pppw + sjmn\npppw: 4\nsjmn: pppw * ppwx\n");
+        assert_eq!((e.line, e.column), (3, 14));
+    }
+
+    #[test]
+    fn parse_error_diagnostic() {
+        let e = parse_error::<Day<9>>("R 4\nU 4\nX 4\n");
+        assert_eq!(
+            e.diagnostic("in9.txt"),
+            "error: expected 'R', 'U', 'L' or 'D'\n --> in9.txt:3:1\n  |\n3 | X 4\n  | ^\n"
+        );
+    }
+
+    #[test]
+    fn no_solution_is_not_a_parse_error() {
+        let cubes = Day::<18>::parse(BufReader::new("".as_bytes())).unwrap();
+        let e = Day::<18>::part2(&cubes, &Config::new()).unwrap_err();
+        assert!(matches!(e, Error::NoSolution(_)));
+        assert_eq!(e.to_string(), "no solution: no lava cubes");
+    }
+}
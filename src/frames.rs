@@ -0,0 +1,162 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use ndarray::Array2;
+
+use crate::{
+    image::{Image, Palette},
+    Config, Error,
+};
+
+enum Target {
+    /// Every frame in one text file, each under a `--- frame N ---` header.
+    Animation(PathBuf),
+    /// One numbered file per frame in a directory, text or an image for `png` and `ppm`.
+    Sequence(PathBuf, String),
+}
+
+/// Collects the board of a grid simulation step by step and writes the frames out at the end.
+///
+/// Solvers make one with `Recorder::from_config`, which reads the `record` parameter: a file
+/// path for an animation, or a path ending in `/` for a numbered sequence, written as images
+/// when `record_format` is `png` or `ppm`. `record_every` keeps only every n-th step. Without
+/// `record` nothing is captured.
+pub struct Recorder {
+    target: Option<Target>,
+    every: usize,
+    steps: usize,
+    frames: Vec<String>,
+    palette: Palette,
+    scale: usize,
+}
+
+impl Recorder {
+    /// A recorder that captures nothing.
+    pub fn off() -> Self {
+        Self {
+            target: None,
+            every: 1,
+            steps: 0,
+            frames: vec![],
+            palette: Palette::default(),
+            scale: 1,
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, Error> {
+        let path: String = config.get("record", String::new())?;
+        let every = config.get("record_every", 1)?.max(1);
+        let format = config.get("record_format", String::from("txt"))?;
+        let target = match path.as_str() {
+            "" => None,
+            p if p.ends_with('/') || Path::new(p).is_dir() => {
+                Some(Target::Sequence(p.into(), format))
+            }
+            p => Some(Target::Animation(p.into())),
+        };
+        Ok(Self {
+            target,
+            every,
+            scale: config.get("image_scale", 4)?.max(1),
+            ..Self::off()
+        })
+    }
+
+    /// Colors for the frames when they are written as images.
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.target.is_some()
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Counts a simulation step, capturing the board `frame` draws when it is one to keep.
+    pub fn step<F: FnOnce() -> String>(&mut self, frame: F) {
+        if self.is_on() && self.steps.is_multiple_of(self.every) {
+            self.frames.push(frame());
+        }
+        self.steps += 1;
+    }
+
+    /// Captures a frame whatever the step, like the final state.
+    pub fn capture<F: FnOnce() -> String>(&mut self, frame: F) {
+        if self.is_on() {
+            self.frames.push(frame());
+        }
+    }
+
+    /// Writes the captured frames to the target.
+    pub fn finish(self) -> Result<(), Error> {
+        match self.target {
+            None => (),
+            Some(Target::Animation(path)) => {
+                let mut text = String::new();
+                for (index, frame) in self.frames.iter().enumerate() {
+                    writeln!(text, "--- frame {} ---\n{}", index, frame.trim_end()).unwrap();
+                }
+                fs::write(path, text)?;
+            }
+            Some(Target::Sequence(dir, format)) => {
+                fs::create_dir_all(&dir)?;
+                for (index, frame) in self.frames.iter().enumerate() {
+                    let path = dir.join(format!("{:04}.{}", index, format));
+                    match format.as_str() {
+                        "png" | "ppm" => Image::from_text(frame, &self.palette)
+                            .scaled(self.scale)
+                            .save(path)?,
+                        _ => fs::write(path, frame)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Draws an `ndarray` board row by row, one `glyph` per cell.
+pub fn grid<T>(board: &Array2<T>, glyph: impl Fn(&T) -> char) -> String {
+    let mut text = String::with_capacity(board.len() + board.nrows());
+    for row in board.rows() {
+        text.extend(row.iter().map(&glyph));
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_recorder() {
+        let board = ndarray::array![[0, 1, 1], [1, 0, 0]];
+        let draw = |&x: &i8| if x > 0 { '#' } else { '.' };
+        assert_eq!(grid(&board, draw), ".##\n#..\n");
+
+        let path = std::env::temp_dir().join("aoc2022_frame_recorder.txt");
+        let config = Config::new()
+            .with("record", path.to_str().unwrap())
+            .with("record_every", "2");
+        let mut recorder = Recorder::from_config(&config).unwrap();
+        for step in 0..5 {
+            recorder.step(|| step.to_string());
+        }
+        assert_eq!(recorder.frames(), ["0", "2", "4"]);
+        recorder.finish().unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with("--- frame 0 ---\n0\n--- frame 1 ---\n2\n"));
+
+        let mut recorder = Recorder::from_config(&Config::new()).unwrap();
+        recorder.step(|| unreachable!());
+        assert!(!recorder.is_on());
+    }
+}
@@ -0,0 +1,122 @@
+use std::ops::RangeInclusive;
+
+/// SplitMix64, small and good enough for puzzle inputs. The same seed always gives the same
+/// numbers, so a generated input can be reproduced from its seed alone.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must not be zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range");
+        lo + self.below((hi - lo) as u64 + 1) as i64
+    }
+
+    /// True with a chance of one in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// Writes random inputs in the format of one puzzle. What the size means depends on the day,
+/// like the number of sensors for 2022 day 15 or the width of a cube face for day 22.
+pub struct Generator {
+    pub year: u32,
+    pub day: u32,
+    /// What the size counts, for the usage and errors.
+    pub size_of: &'static str,
+    pub default_size: usize,
+    generate: GenerateFn,
+}
+
+impl Generator {
+    pub(crate) const fn new(
+        year: u32,
+        day: u32,
+        size_of: &'static str,
+        default_size: usize,
+        generate: GenerateFn,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            size_of,
+            default_size,
+            generate,
+        }
+    }
+
+    /// A whole input, the default size when `size` is `None`. Generators clamp sizes the puzzle
+    /// can't take, like a single valve.
+    pub fn generate(&self, seed: u64, size: Option<usize>) -> String {
+        (self.generate)(&mut Rng::new(seed), size.unwrap_or(self.default_size))
+    }
+}
+
+#[rustfmt::skip]
+const YEARS: &[&[Generator]] = &[
+    crate::y2022::GENERATORS,
+];
+
+pub fn generators() -> impl Iterator<Item = &'static Generator> {
+    YEARS.iter().flat_map(|generators| generators.iter())
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Generator> {
+    generators().find(|g| g.year == year && g.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::io::{BufReader, Cursor, Read};
+
+    #[test]
+    fn generated_inputs_parse() {
+        for generator in generators() {
+            let text = generator.generate(7, Some(6));
+            assert_eq!(text, generator.generate(7, Some(6)));
+            assert_ne!(text, generator.generate(8, Some(6)));
+
+            let solver = registry::find(generator.year, generator.day, 1).unwrap();
+            let reader: Box<dyn Read> = Box::new(Cursor::new(text));
+            let parsed = solver.parse(BufReader::new(reader));
+            assert!(parsed.is_ok(), "day {}: {:?}", generator.day, parsed.err());
+        }
+
+        let mut rng = Rng::new(1);
+        assert!((0..100).all(|_| (-2..=2).contains(&rng.range(-2..=2))));
+        assert!((0..100).all(|_| rng.below(3) < 3));
+    }
+}
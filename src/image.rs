@@ -0,0 +1,267 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::{Config, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// The color `t` of the way from `self` to `other`, `t` going from 0 to 1.
+    pub fn mix(self, other: Rgb, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// Colors for the characters of a text grid.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    fallback: Rgb,
+}
+
+impl Palette {
+    /// A palette drawing every character with `fallback`.
+    pub fn new(fallback: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            fallback,
+        }
+    }
+
+    pub fn with(mut self, c: char, color: Rgb) -> Self {
+        self.colors.insert(c, color);
+        self
+    }
+
+    pub fn color(&self, c: char) -> Rgb {
+        *self.colors.get(&c).unwrap_or(&self.fallback)
+    }
+}
+
+impl Default for Palette {
+    /// Dark empty tiles, light walls and red for anything else, which is usually a marker.
+    fn default() -> Self {
+        Self::new(Rgb(230, 60, 60))
+            .with(' ', Rgb::BLACK)
+            .with('.', Rgb(40, 40, 48))
+            .with('#', Rgb(200, 200, 200))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// One pixel per character, lines shorter than the longest padded with the fallback color.
+    pub fn from_text(text: &str, palette: &Palette) -> Self {
+        let lines = text.lines().collect::<Vec<_>>();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut image = Self::new(width, lines.len(), palette.fallback);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                image.set(x, y, palette.color(c));
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Colors a pixel, ignoring coordinates outside the image.
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// Copies `other` in with its top left corner at `x`, `y`.
+    pub fn blit(&mut self, other: &Image, x: usize, y: usize) {
+        for oy in 0..other.height {
+            for ox in 0..other.width {
+                self.set(x + ox, y + oy, other.pixels[oy * other.width + ox]);
+            }
+        }
+    }
+
+    /// Every pixel becomes a `factor` by `factor` square.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let mut image = Self::new(self.width * factor, self.height * factor, Rgb::BLACK);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                image.pixels[y * image.width + x] =
+                    self.pixels[y / factor * self.width + x / factor];
+            }
+        }
+        image
+    }
+
+    /// Binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for &Rgb(r, g, b) in &self.pixels {
+            out.extend([r, g, b]);
+        }
+        out
+    }
+
+    /// 8-bit RGB PNG. The image data is stored without compression.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            // Filter type 0, the row as is.
+            raw.push(0);
+            for &Rgb(r, g, b) in row {
+                raw.extend([r, g, b]);
+            }
+        }
+
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // Bit depth 8, color type 2 (RGB), default compression, filtering and no
+        // interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Writes a PPM for a `.ppm` path and a PNG otherwise.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            _ => self.to_png(),
+        };
+        fs::write(path, bytes)?;
+        Ok(())
+    }
+}
+
+/// Saves the image `draw` makes to the path in the `image` parameter, if there is one, with
+/// every cell `image_scale` pixels wide (default 4).
+pub fn export<F: FnOnce() -> Image>(config: &Config, draw: F) -> Result<(), Error> {
+    let path: String = config.get("image", String::new())?;
+    if !path.is_empty() {
+        let scale = config.get("image_scale", 4)?.max(1);
+        draw().scaled(scale).save(path)?;
+    }
+    Ok(())
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut table = [0u32; 256];
+    for (n, entry) in table.iter_mut().enumerate() {
+        let mut c = n as u32;
+        for _ in 0..8 {
+            c = if c & 1 != 0 {
+                0xedb88320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+        }
+        *entry = c;
+    }
+
+    let mut crc = 0xffffffffu32;
+    for &byte in data {
+        crc = table[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc ^ 0xffffffff
+}
+
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_encoders() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+
+        let palette = Palette::new(Rgb::BLACK).with('#', Rgb::WHITE);
+        let img = Image::from_text("#.\n.#", &palette).scaled(2);
+        assert_eq!((img.width(), img.height()), (4, 4));
+        assert_eq!(img.get(1, 1), Some(Rgb::WHITE));
+        assert_eq!(img.get(2, 1), Some(Rgb::BLACK));
+        assert_eq!(img.get(4, 0), None);
+
+        let ppm = Image::from_text("#.", &palette).to_ppm();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xff\xff\xff\x00\x00\x00");
+
+        let png = img.to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR"));
+        assert!(png.ends_with(b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"));
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    env,
+    fs::File,
+    io::{BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+use ndarray::Array2;
+
+use crate::{error::ParseError, Error};
+
+/// Environment variable pointing at the directory with the puzzle inputs.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+pub const INPUTS_DIR: &str = "inputs";
+
+/// The inputs directory, `inputs` unless overridden by `AOC_INPUTS`.
+pub fn dir() -> PathBuf {
+    env::var_os(INPUTS_ENV).map_or_else(|| INPUTS_DIR.into(), PathBuf::from)
+}
+
+/// The inputs of one year, in a directory named after it.
+pub fn year_dir(year: u32) -> PathBuf {
+    dir().join(year.to_string())
+}
+
+/// `in<day>.txt`, or `in<day>_<example>.txt` for a named example like `small`.
+pub fn file_name(day: u32, example: Option<&str>) -> String {
+    match example {
+        Some(example) => format!("in{}_{}.txt", day, example),
+        None => format!("in{}.txt", day),
+    }
+}
+
+/// Where the input for `day` of `year` is found by convention.
+pub fn path(year: u32, day: u32, example: Option<&str>) -> PathBuf {
+    year_dir(year).join(file_name(day, example))
+}
+
+/// Opens an input file, or stdin for `-`.
+pub fn open<P: AsRef<Path>>(path: P) -> Result<BufReader<Box<dyn Read>>, Error> {
+    let path = path.as_ref();
+    let reader: Box<dyn Read> = if path == Path::new("-") {
+        Box::new(std::io::stdin())
+    } else {
+        Box::new(File::open(path)?)
+    };
+    Ok(BufReader::new(reader))
+}
+
+/// Reads and normalizes an input file, or stdin for `-`.
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Text, Error> {
+    Text::read(open(path)?)
+}
+
+/// Puzzle input the way parsers expect it whatever saved it: no byte order mark, `\n` line
+/// endings, no whitespace at the end of lines and no blank lines at the end. Line numbers stay
+/// those of the original.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Text(String);
+
+/// Lines between blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// 1-based number of the first line, for error messages.
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl Text {
+    pub fn new(raw: &str) -> Self {
+        let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+        let mut lines = raw.lines().map(str::trim_end).collect::<Vec<_>>();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        let mut text = lines.join("\n");
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        Self(text)
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut raw = String::new();
+        reader.read_to_string(&mut raw)?;
+        Ok(Self::new(&raw))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The normalized text, for parsers that read line by line.
+    pub fn into_reader(self) -> BufReader<Cursor<String>> {
+        BufReader::new(Cursor::new(self.0))
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.0.lines()
+    }
+
+    /// Groups of lines separated by one or more blank lines.
+    pub fn blocks(&self) -> Vec<Block<'_>> {
+        let mut blocks: Vec<Block> = vec![];
+        let mut in_block = false;
+        for (index, line) in self.lines().enumerate() {
+            match (line.is_empty(), in_block) {
+                (true, _) => in_block = false,
+                (false, true) => blocks.last_mut().unwrap().lines.push(line),
+                (false, false) => {
+                    blocks.push(Block {
+                        line: index + 1,
+                        lines: vec![line],
+                    });
+                    in_block = true;
+                }
+            }
+        }
+        blocks
+    }
+
+    /// The characters of a rectangular block of text, indexed by row and column.
+    pub fn grid(&self) -> Result<Array2<char>, ParseError> {
+        let rows = self.lines().collect::<Vec<_>>();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        if width == 0 {
+            return Err(ParseError::missing("a grid"));
+        }
+        for (index, row) in rows.iter().enumerate() {
+            if row.chars().count() != width {
+                let end = row.char_indices().nth(width).map_or(row.len(), |(i, _)| i);
+                let expected = format!("a row of {} characters", width);
+                return Err(ParseError::at(index + 1, row, &row[end..], expected));
+            }
+        }
+        let cells = rows.iter().flat_map(|row| row.chars()).collect();
+        Ok(Array2::from_shape_vec((rows.len(), width), cells).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{registry, Answer::Int, Config, Day, Error, Problem};
+    use std::io::BufReader;
+
+    #[test]
+    fn inputs_by_convention() {
+        assert_eq!(file_name(9, None), "in9.txt");
+        assert_eq!(file_name(15, Some("small")), "in15_small.txt");
+        assert_eq!(path(2022, 9, None), dir().join("2022").join("in9.txt"));
+        assert!(path(2022, 15, Some("small")).exists());
+        assert!(matches!(open(""), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn normalized_input() {
+        let text = Text::new("\u{feff}1000 \r\n2000\r\n\r\n\r\n3000\r\n  \r\n\r\n");
+        assert_eq!(text.as_str(), "1000\n2000\n\n\n3000\n");
+        let blocks = text.blocks();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!((blocks[1].line, &blocks[1].lines[..]), (5, &["3000"][..]));
+
+        let grid = Text::new("#..\n.#.\n").grid().unwrap();
+        assert_eq!((grid.dim(), grid[[1, 1]]), ((2, 3), '#'));
+        let e = Text::new("#..\n.#.#\n").grid().unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+
+        let windows = |path: &std::path::Path| {
+            let raw = std::fs::read_to_string(path).unwrap();
+            format!("\u{feff}{}\r\n\r\n", raw.replace('\n', "\r\n"))
+        };
+        let day1 = windows(&path(2022, 1, None));
+        let answers = Day::<1>::solve_buffer(BufReader::new(day1.as_bytes()), &Config::new());
+        assert_eq!(answers.unwrap()[0], Int(69528));
+        let path = path(2022, 23, Some("small"));
+        assert_eq!(
+            registry::find(2022, 23, 1)
+                .unwrap()
+                .parse(open(&path).unwrap())
+                .unwrap()
+                .downcast_ref::<<Day<23> as Problem>::Input>(),
+            Some(&Day::<23>::parse(Text::new(&windows(&path)).into_reader()).unwrap())
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::{io::BufReader, path::Path};
+
+pub use answer::Answer;
+pub use config::Config;
+pub use error::Error;
+
+pub mod answer;
+pub mod bench;
+pub mod cancel;
+pub mod config;
+mod day_template;
+pub mod differential;
+pub mod dot;
+pub mod error;
+pub mod frames;
+pub mod generate;
+pub mod image;
+pub mod inputs;
+pub mod pattern;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod simulation;
+pub mod trace;
+pub mod verify;
+pub mod y2022;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// The year of a `Day` without one, the year this crate started with.
+pub const DEFAULT_YEAR: u32 = 2022;
+
+pub struct Day<const T: usize, const Y: u32 = DEFAULT_YEAR> {}
+
+pub trait Problem {
+    /// The puzzle input in parsed form, shared by both parts.
+    type Input;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read;
+
+    fn part1(input: &Self::Input, config: &Config) -> Result<Answer, Error>;
+
+    fn part2(_input: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Err(Error::NotImplemented)
+    }
+
+    /// Parses an input file, or stdin for `-`, normalized like `inputs::Text`.
+    fn parse_file<P>(path: P) -> Result<Self::Input, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Self::parse(inputs::read(path)?.into_reader())
+    }
+
+    /// Solves an input file, or stdin for `-`.
+    fn solve_file<P>(path: P, config: &Config) -> Result<Vec<Answer>, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Self::solve_buffer(inputs::open(path)?, config)
+    }
+
+    /// Parses the normalized input once and solves every part that is implemented.
+    fn solve_buffer<T>(reader: BufReader<T>, config: &Config) -> Result<Vec<Answer>, Error>
+    where
+        T: std::io::Read,
+    {
+        let input = Self::parse(inputs::Text::read(reader)?.into_reader())?;
+        let mut answers = vec![Self::part1(&input, config)?];
+        match Self::part2(&input, config) {
+            Ok(answer) => answers.push(answer),
+            Err(Error::NotImplemented) => (),
+            Err(e) => return Err(e),
+        }
+        Ok(answers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::ParseError, inputs, Config, Day, Error, Problem};
+    use std::io::BufReader;
+
+    /// The error parsing `input` with `P` fails with, for the tests of where errors point.
+    pub(crate) fn parse_error<P: Problem>(input: &str) -> ParseError {
+        match P::parse(BufReader::new(input.as_bytes())) {
+            Err(Error::Parse(e)) => e,
+            Err(other) => panic!("expected a parse error, got {:?}", other),
+            Ok(_) => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_day() {
+        Day::<0>::solve_file(inputs::path(2022, 1, None), &Config::new()).unwrap();
+    }
+
+    #[test]
+    fn test_day_badfile() {
+        assert!(matches!(
+            Day::<0>::solve_file("", &Config::new()),
+            Err(Error::Io(_))
+        ));
+    }
+}
@@ -1,551 +1,540 @@
-use std::{fs::File, io::BufReader, path::Path};
+use aoc2022::{
+    bench, cancel::Cancel, differential, generate, inputs, registry, runner, scaffold, trace,
+    verify, verify::Verdict, Config, Error,
+};
+use cli::{Command, Format};
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day16_part1;
-mod day16_part2;
-mod day17;
-// mod day17_optimized;
-mod day17_pretty;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day_template;
+mod cli;
 
-fn main() {
-    let mut writer = std::io::Cursor::new(vec![]);
-    println!("Hey!");
-    let t0 = std::time::Instant::now();
-
-    //Day19::<24, false>::solve_file("in19_small.txt", &mut writer);
-    // Day19::<32, true>::solve_file("in19_small.txt", &mut writer);
-    //Day19::<24, false>::solve_file("in19.txt", &mut writer);
-    //Day19::<32, true>::solve_file("in19.txt", &mut writer);
-    Day::<24>::solve_file("in24_small.txt", &mut writer);
-    // Day::<24>::solve_file("in24.txt", &mut writer);
-    println!("Time: {:?}", t0.elapsed());
-}
-
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
-enum Outcome {
-    Loss,
-    Draw,
-    Win,
+/// Parameters are read by whichever solver knows them, so a typo would go unnoticed otherwise.
+fn warn_unused(config: &Config) {
+    for key in config.unused() {
+        eprintln!("warning: no solver used the parameter '{}'", key);
+    }
 }
 
-struct Day<const T: usize> {}
+fn main() {
+    let parsed = cli::split_log(std::env::args().skip(1)).and_then(|(filter, args)| {
+        trace::init(filter);
+        Command::parse(args.into_iter())
+    });
+    let command = match parsed {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
 
-trait Problem {
-    fn solve_file<P, W>(path: P, writer: &mut W)
-    where
-        P: AsRef<Path> + Copy,
-        W: std::io::Write,
-    {
-        let file = File::open(path).expect("File could not be opened.");
-        Self::solve_buffer(BufReader::new(file), writer)
+    match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            example,
+            timeout,
+            config,
+        } => {
+            let year = year.unwrap_or_else(registry::latest_year);
+            let solver = match registry::find(year, day, part) {
+                Some(solver) => solver,
+                None => {
+                    eprintln!("error: no solver for {} day {} part {}", year, day, part);
+                    std::process::exit(1);
+                }
+            };
+            let input = match input {
+                Some(input) => input,
+                None => inputs::path(year, day, example.as_deref())
+                    .display()
+                    .to_string(),
+            };
+            let mut cancel = Cancel::new();
+            if let Some(timeout) = timeout {
+                cancel = cancel.with_timeout(timeout);
+            }
+            let config = config.with_cancel(cancel);
+            eprintln!(
+                "--- {} Day {}: {} (part {}) ---",
+                year, day, solver.name, part
+            );
+            let t0 = std::time::Instant::now();
+            match solver.solve_file(&input, &config) {
+                Ok(answer) => println!("{}", answer),
+                Err(Error::Io(e)) => {
+                    eprintln!("error: could not read '{}': {}", input, e);
+                    std::process::exit(1);
+                }
+                Err(Error::Parse(e)) => {
+                    eprint!("{}", e.diagnostic(&input));
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            warn_unused(&config);
+            eprintln!("Time: {:?}", t0.elapsed());
+        }
+        Command::RunAll {
+            year,
+            jobs,
+            timeout,
+            config,
+        } => {
+            let jobs =
+                jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+            let solvers = registry::select(year, None, None).collect::<Vec<_>>();
+            let t0 = std::time::Instant::now();
+            let runs = runner::run_all(&solvers, &config, jobs, timeout);
+            runner::print_table(&runs);
+            warn_unused(&config);
+
+            let unsolved = runs.iter().filter(|run| !run.is_solved()).count();
+            println!(
+                "{} solved, {} failed in {:?}",
+                runs.len() - unsolved,
+                unsolved,
+                t0.elapsed()
+            );
+            if unsolved > 0 {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            part,
+            runs,
+            format,
+            config,
+        } => {
+            let mut measurements = vec![];
+            for solver in registry::select(year, day, part) {
+                eprintln!(
+                    "Benchmarking {} day {} part {}...",
+                    solver.year, solver.day, solver.part
+                );
+                match bench::measure(solver, &solver.input(), runs, &config) {
+                    Ok(m) => measurements.push(m),
+                    Err(e) => eprintln!(
+                        "error: {} day {} part {}: {}",
+                        solver.year, solver.day, solver.part, e
+                    ),
+                }
+            }
+
+            match format {
+                Format::Text => bench::print_text(&measurements),
+                Format::Json => println!("{}", bench::to_json(&measurements)),
+            }
+            warn_unused(&config);
+        }
+        Command::Verify {
+            year,
+            day,
+            part,
+            answers,
+        } => {
+            let path = answers.as_deref().unwrap_or(verify::ANSWERS_FILE);
+            let text = match std::fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(e) => {
+                    eprintln!("error: could not read '{}': {}", path, e);
+                    std::process::exit(1);
+                }
+            };
+            let known = match verify::parse(&text) {
+                Ok(known) => known,
+                Err(e) => {
+                    eprint!("{}", e.diagnostic(path));
+                    std::process::exit(1);
+                }
+            };
+
+            let (mut passed, mut mismatched, mut failed) = (0, 0, 0);
+            for k in known.iter().filter(|k| {
+                year.unwrap_or(k.year) == k.year
+                    && day.unwrap_or(k.day) == k.day
+                    && part.unwrap_or(k.part) == k.part
+            }) {
+                let mut label = format!("{} day {:>2} part {} {}", k.year, k.day, k.part, k.input);
+                if !k.config.is_empty() {
+                    label = format!("{} [{}]", label, k.config);
+                }
+                match verify::check(k) {
+                    Verdict::Pass => {
+                        passed += 1;
+                        println!("PASS      {}", label);
+                    }
+                    Verdict::Mismatch { actual } => {
+                        mismatched += 1;
+                        println!("MISMATCH  {}: expected {}, got {}", label, k.answer, actual);
+                    }
+                    Verdict::Fail(e) => {
+                        failed += 1;
+                        println!("FAIL      {}: {}", label, e);
+                    }
+                }
+            }
+
+            println!(
+                "{} passed, {} mismatched, {} failed",
+                passed, mismatched, failed
+            );
+            if mismatched + failed > 0 {
+                std::process::exit(1);
+            }
+        }
+        Command::NewDay { year, day, name } => {
+            let year = year.unwrap_or_else(registry::latest_year);
+            let name = name.unwrap_or_else(|| format!("Day {}", day));
+            match scaffold::new_day(std::path::Path::new("."), year, day, &name) {
+                Ok(written) => {
+                    for path in written {
+                        println!("wrote {}", path.display());
+                    }
+                    println!("Fill in the input and its answer in {}.", verify::ANSWERS_FILE);
+                }
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let year = year.unwrap_or_else(registry::latest_year);
+            let generator = match generate::find(year, day) {
+                Some(generator) => generator,
+                None => {
+                    let days = generate::generators()
+                        .filter(|g| g.year == year)
+                        .map(|g| g.day.to_string())
+                        .collect::<Vec<_>>();
+                    eprintln!(
+                        "error: no generator for {} day {}, there are ones for days {}",
+                        year,
+                        day,
+                        days.join(", ")
+                    );
+                    std::process::exit(1);
+                }
+            };
+            let seed = seed.unwrap_or_else(|| {
+                let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
+                now.map_or(0, |t| t.as_nanos() as u64)
+            });
+            eprintln!(
+                "{} day {} with seed {} and {} {}",
+                year,
+                day,
+                seed,
+                size.unwrap_or(generator.default_size),
+                generator.size_of
+            );
+            let text = generator.generate(seed, size);
+            match output {
+                Some(path) => {
+                    if let Err(e) = std::fs::write(&path, text) {
+                        eprintln!("error: could not write '{}': {}", path, e);
+                        std::process::exit(1);
+                    }
+                }
+                None => print!("{}", text),
+            }
+        }
+        Command::Compare {
+            year,
+            day,
+            part,
+            seed,
+            runs,
+            size,
+        } => {
+            let mut diverged = 0;
+            for pair in differential::pairs().filter(|p| {
+                year.unwrap_or(p.year) == p.year
+                    && day.unwrap_or(p.day) == p.day
+                    && part.unwrap_or(p.part) == p.part
+            }) {
+                let [left, right] = pair.names;
+                let label = format!(
+                    "{} day {} part {}, {} vs {}",
+                    pair.year, pair.day, pair.part, left, right
+                );
+                match pair.check(seed, runs, size, &Config::new()) {
+                    None => println!("AGREE     {} on {} inputs", label, runs),
+                    Some(d) => {
+                        diverged += 1;
+                        println!(
+                            "DISAGREE  {} on seed {}, down to this input:",
+                            label, d.seed
+                        );
+                        print!("{}", d.input);
+                        for (name, outcome) in pair.names.iter().zip(d.outcomes) {
+                            match outcome {
+                                Ok(answer) => println!("{}: {}", name, answer),
+                                Err(e) => println!("{}: error: {}", name, e),
+                            }
+                        }
+                    }
+                }
+            }
+            if diverged > 0 {
+                std::process::exit(1);
+            }
+        }
+        Command::Help => print!("{}", cli::USAGE),
     }
-
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
-    where
-        T: std::io::Read,
-        W: std::io::Write;
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        day11::Day11,
-        day14::Day14,
-        day15::{Day15, DefinitelyNoBeaconsAtLine, FindTheLoneOutOfRangeTile},
-        // day17_optimized::Day17Optimized,
-        day17_pretty::Day17,
-        day20::Day20,
-        day22::Day22,
-        day3::{Day3CommonItemInCompartments, Day3CommonItemInGroups},
-        day4::{Day4, OneFullyInsideAnother, Overlap},
-        day5::{CrateMover9000, CrateMover9001, Day5},
-        day6::Day6,
-        Day,
-        Problem,
+    use crate::cli::{self, Command, Format};
+    use aoc2022::{
+        trace::{Filter, Level},
+        Config,
     };
-
-    #[test]
-    fn test_day() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<0>::solve_file("in1.txt", &mut writer);
-    }
-
-    #[test]
-    #[should_panic]
-    fn test_day_badfile() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<0>::solve_file("", &mut writer);
-    }
-
-    #[test]
-    fn day1() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1>::solve_file("in1.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("69528"));
-        assert!(output.contains("206152"));
-    }
-
-    #[test]
-    fn day2() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<2>::solve_file("in2.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("13052"));
-        assert!(output.contains("13693"));
-    }
-
-    #[test]
-    fn day3_compartments() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day3CommonItemInCompartments::solve_file("in3.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("7908"));
-    }
-
-    #[test]
-    fn day3_groups() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day3CommonItemInGroups::solve_file("in3.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("2838"));
-    }
-
-    #[test]
-    fn day4_fullyinside() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day4::<OneFullyInsideAnother>::solve_file("in4.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("450"));
-    }
-
-    #[test]
-    fn day4_overlap() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day4::<Overlap>::solve_file("in4.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("837"));
-    }
-
-    #[test]
-    fn day5_cratemover9000() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day5::<CrateMover9000>::solve_file("in5.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("RLFNRTNFB"));
-    }
-
-    #[test]
-    fn day5_cratemover9001() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day5::<CrateMover9001>::solve_file("in5.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("MHQTLJRLB"));
-    }
-
-    #[test]
-    fn day6_signal() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day6::<4>::solve_file("in6.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("1142"));
-    }
-
-    #[test]
-    fn day6_message() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day6::<14>::solve_file("in6.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("2803"));
-    }
-
-    #[test]
-    fn day7_small() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<7>::solve_file("in7_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("95437"));
-        assert!(output.contains("24933642"));
-    }
-
-    #[test]
-    fn day7() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<7>::solve_file("in7.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("1477771"));
-        assert!(output.contains("3579501"));
-    }
-
-    #[test]
-    fn day8_small() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<8>::solve_file("in8_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("21"));
-        assert!(output.contains('8'));
-    }
-
-    #[test]
-    fn day8() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<8>::solve_file("in8.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("1705"));
-        assert!(output.contains("371200"));
-    }
-
-    #[test]
-    fn day9() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<9>::solve_file("in9.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("6197"));
-        assert!(output.contains("2562"));
-    }
-
-    #[test]
-    fn day10_signal_strength() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<101>::solve_file("in10.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("14220"));
-    }
-
-    #[test]
-    fn day10_crt() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<102>::solve_file("in10.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains(
-            r#"####.###...##..###..#....####.####.#..#.
-...#.#..#.#..#.#..#.#....#.......#.#..#.
-..#..#..#.#..#.#..#.#....###....#..#..#.
-.#...###..####.###..#....#.....#...#..#.
-#....#.#..#..#.#.#..#....#....#....#..#.
-####.#..#.#..#.#..#.####.#....####..##.."#
-        ));
-    }
-
-    #[test]
-    fn day11() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day11::<3, 20>::solve_file("in11.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("316888"));
-    }
-
-    #[test]
-    fn day11_big_stress() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day11::<1, 10000>::solve_file("in11.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("35270398814"));
-    }
-
-    #[test]
-    fn day12_uphill() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1201>::solve_file("in12.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "412");
-    }
-
-    #[test]
-    fn day12_downhill() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1202>::solve_file("in12.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "402");
-    }
-
-    #[test]
-    fn day13_already_correct_order() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1301>::solve_file("in13.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("5623"));
-    }
-
-    #[test]
-    fn day13_delimiters() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1302>::solve_file("in13.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("20570"));
-    }
-
-    #[test]
-    fn day14_abyss() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day14::<false>::solve_file("in14.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "768");
-    }
-
-    #[test]
-    fn day14_floor() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day14::<true>::solve_file("in14.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "26686");
-    }
-
-    #[test]
-    fn day15_line2000000() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day15::<DefinitelyNoBeaconsAtLine<2000000>>::solve_file("in15.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("5125700"));
-    }
-
-    #[test]
-    #[ignore]
-    fn day15_out_of_range() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day15::<FindTheLoneOutOfRangeTile<4000000>>::solve_file("in15.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("11379394658764"));
-    }
-
-    #[test]
-    fn day15_small_line10() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day15::<DefinitelyNoBeaconsAtLine<10>>::solve_file("in15_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("26"));
-    }
-
-    #[test]
-    fn day15_small_out_of_range() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day15::<FindTheLoneOutOfRangeTile<21>>::solve_file("in15_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert!(output.contains("56000011"));
-    }
-
-    #[test]
-    fn day16_alone() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1601>::solve_file("in16.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "2253");
-    }
-
-    #[test]
-    fn day16_with_elephant() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1602>::solve_file("in16.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "2838");
-    }
-
-    #[test]
-    fn day17_tetris_pretty_but_bad() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day17::<2022>::solve_file("in17.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "3159");
-    }
-
-    #[test]
-    #[cfg(disabled)]
-    fn day17_tetris_opt() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day17Optimized::<2022>::solve_file("in17.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "3159");
-    }
-
-    #[test]
-    #[cfg(disabled)]
-    fn day17_tetris_opt_big() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day17Optimized::<1_000_000_000_000>::solve_file("in17.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "1566272189352");
-    }
-
-    #[test]
-    fn day18_surface_area() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1801>::solve_file("in18.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "4332");
-    }
-
-    #[test]
-    fn day18_outside_surface_area() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<1802>::solve_file("in18.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "2524");
-    }
-
-    #[test]
-    fn day20_shuffle() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day20::<1, 1>::solve_file("in20.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "4066");
-    }
-
-    #[test]
-    fn day20_decryption_key() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day20::<10, 811589153>::solve_file("in20.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "6704537992933");
-    }
-
-    #[test]
-    fn day21_eval() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<2101>::solve_file("in21.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "83056452926300");
-    }
-
-    #[test]
-    fn day21_force_result() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<2102>::solve_file("in21.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "3469704905529");
-    }
-
-    #[test]
-    fn day22_small1() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day22::<false, 4>::solve_file("in22_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "6032");
-    }
-
-    #[test]
-    fn day22_small2() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day22::<true, 4>::solve_file("in22_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "5031");
-    }
-
-    #[test]
-    fn day22_big1() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day22::<false, 50>::solve_file("in22.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "103224");
-    }
-
-    #[test]
-    fn day22_big2() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day22::<true, 50>::solve_file("in22.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "189097");
-    }
-
-    #[test]
-    fn day23_part1_small() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<2301>::solve_file("in23_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "110");
-    }
-
-    #[test]
-    fn day23_part2_small() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<2302>::solve_file("in23_small.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "20");
-    }
-
-    #[test]
-    fn day23_part1() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<2301>::solve_file("in23.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "4195");
-    }
-
-    #[test]
-    fn day23_part2() {
-        let mut writer = std::io::Cursor::new(vec![]);
-        Day::<2302>::solve_file("in23.txt", &mut writer);
-        let output_raw = writer.into_inner();
-        let output = std::str::from_utf8(&output_raw).unwrap().trim();
-        assert_eq!(output, "1069");
+    use std::time::Duration;
+
+    fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+        line.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn cli_run() {
+        assert_eq!(
+            Command::parse(args("run --day 16 --part 2 --input in16.txt")),
+            Ok(Command::Run {
+                year: None,
+                day: 16,
+                part: 2,
+                input: Some("in16.txt".into()),
+                example: None,
+                timeout: None,
+                config: Config::new(),
+            })
+        );
+        assert_eq!(
+            Command::parse(args("run -d 3")),
+            Ok(Command::Run {
+                year: None,
+                day: 3,
+                part: 1,
+                input: None,
+                example: None,
+                timeout: None,
+                config: Config::new(),
+            })
+        );
+        assert_eq!(
+            Command::parse(args(
+                "run -d 15 -i inputs/in15_small.txt --set row=10 -s size=21"
+            )),
+            Ok(Command::Run {
+                year: None,
+                day: 15,
+                part: 1,
+                input: Some("inputs/in15_small.txt".into()),
+                example: None,
+                timeout: None,
+                config: Config::new().with("row", "10").with("size", "21"),
+            })
+        );
+        assert_eq!(
+            Command::parse(args("run -d 23 -r frames/ -s record_every=10")),
+            Ok(Command::Run {
+                year: None,
+                day: 23,
+                part: 1,
+                input: None,
+                example: None,
+                timeout: None,
+                config: Config::new()
+                    .with("record", "frames/")
+                    .with("record_every", "10"),
+            })
+        );
+        assert_eq!(
+            Command::parse(args("run -d 16 --dot valves.dot")),
+            Ok(Command::Run {
+                year: None,
+                day: 16,
+                part: 1,
+                input: None,
+                example: None,
+                timeout: None,
+                config: Config::new().with("dot", "valves.dot"),
+            })
+        );
+    }
+
+    #[test]
+    fn cli_bench() {
+        assert_eq!(
+            Command::parse(args("bench -d 17 --runs 20 --format json")),
+            Ok(Command::Bench {
+                year: None,
+                day: Some(17),
+                part: None,
+                runs: 20,
+                format: Format::Json,
+                config: Config::new(),
+            })
+        );
+        assert!(Command::parse(args("bench --format yaml")).is_err());
+        assert!(Command::parse(args("bench --runs 0")).is_err());
+    }
+
+    #[test]
+    fn cli_run_example() {
+        let run = |line| match Command::parse(args(line)) {
+            Ok(Command::Run { input, example, .. }) => (input, example),
+            other => panic!("expected a run command, got {:?}", other),
+        };
+        assert_eq!(run("run -d 15 --example"), (None, Some("small".into())));
+        assert_eq!(
+            run("run -d 15 --example big -p 2"),
+            (None, Some("big".into()))
+        );
+        assert_eq!(
+            run("run -d 15 --example -p 2"),
+            (None, Some("small".into()))
+        );
+        assert_eq!(run("run -d 1 -i -"), (Some("-".into()), None));
+        assert!(Command::parse(args("run -d 15 -i in15.txt --example")).is_err());
+    }
+
+    #[test]
+    fn cli_run_all() {
+        assert_eq!(
+            Command::parse(args("run-all -j 4 -s row=10")),
+            Ok(Command::RunAll {
+                year: None,
+                jobs: Some(4),
+                timeout: None,
+                config: Config::new().with("row", "10"),
+            })
+        );
+        assert_eq!(
+            Command::parse(args("run-all --timeout 2.5")),
+            Ok(Command::RunAll {
+                year: None,
+                jobs: None,
+                timeout: Some(Duration::from_millis(2500)),
+                config: Config::new(),
+            })
+        );
+        assert!(Command::parse(args("run-all -t -1")).is_err());
+    }
+
+    #[test]
+    fn cli_run_bad_args() {
+        assert!(Command::parse(args("run --part 1")).is_err());
+        assert!(Command::parse(args("run --day x")).is_err());
+        assert!(Command::parse(args("run --day 1 --part 3")).is_err());
+        assert!(Command::parse(args("fly")).is_err());
+        assert!(Command::parse(args("run-all --jobs 0")).is_err());
+        assert!(Command::parse(args("run --day 15 --set row")).is_err());
+        assert!(Command::parse(args("run --day 14 --record")).is_err());
+    }
+
+    #[test]
+    fn cli_year() {
+        let year = |line| match Command::parse(args(line)) {
+            Ok(
+                Command::Run { year, .. }
+                | Command::RunAll { year, .. }
+                | Command::Bench { year, .. }
+                | Command::Verify { year, .. }
+                | Command::NewDay { year, .. }
+                | Command::Generate { year, .. },
+            ) => year,
+            other => panic!("expected a command with a year, got {:?}", other),
+        };
+        assert_eq!(year("run --year 2022 -d 5"), Some(2022));
+        assert_eq!(year("run -d 5"), None);
+        assert_eq!(year("run-all -y 2022"), Some(2022));
+        assert_eq!(year("bench -y 2022 -d 17"), Some(2022));
+        assert_eq!(year("verify -y 2022"), Some(2022));
+        assert_eq!(year("new-day -y 2023 -d 1"), Some(2023));
+        assert_eq!(year("generate -y 2022 -d 15"), Some(2022));
+        assert!(Command::parse(args("run -y twenty -d 5")).is_err());
+    }
+
+    #[test]
+    fn cli_new_day() {
+        assert_eq!(
+            Command::parse(
+                ["new-day", "-d", "25", "--name", "Full of Hot Air"]
+                    .map(String::from)
+                    .into_iter()
+            ),
+            Ok(Command::NewDay {
+                year: None,
+                day: 25,
+                name: Some("Full of Hot Air".into()),
+            })
+        );
+        assert!(Command::parse(args("new-day")).is_err());
+        assert!(Command::parse(args("new-day -d 26")).is_err());
+    }
+
+    #[test]
+    fn cli_generate() {
+        assert_eq!(
+            Command::parse(args("generate -d 16 --seed 7 --size 20 -o in16_random.txt")),
+            Ok(Command::Generate {
+                year: None,
+                day: 16,
+                seed: Some(7),
+                size: Some(20),
+                output: Some("in16_random.txt".into()),
+            })
+        );
+        assert!(Command::parse(args("generate --seed 7")).is_err());
+        assert!(Command::parse(args("generate -d 16 --size 0")).is_err());
+        assert!(Command::parse(args("generate -d 16 --seed -1")).is_err());
+    }
+
+    #[test]
+    fn cli_compare() {
+        assert_eq!(
+            Command::parse(args("compare -d 12 --seed 40 -n 10")),
+            Ok(Command::Compare {
+                year: None,
+                day: Some(12),
+                part: None,
+                seed: 40,
+                runs: 10,
+                size: None,
+            })
+        );
+        assert!(Command::parse(args("compare --runs 0")).is_err());
+        assert!(Command::parse(args("compare -p 3")).is_err());
+    }
+
+    #[test]
+    fn cli_log() {
+        let (filter, rest) = cli::split_log(args("-v run --log day22=trace -d 22")).unwrap();
+        assert_eq!(
+            filter,
+            Filter::new()
+                .with_level(Some(Level::Info))
+                .with_target("day22", Level::Trace)
+        );
+        assert_eq!(rest, ["run", "-d", "22"]);
+
+        let (filter, _) = cli::split_log(args("run -d 1")).unwrap();
+        assert_eq!(filter, Filter::new());
+        assert!(cli::split_log(args("run --log day22=loud")).is_err());
     }
 }
@@ -0,0 +1,172 @@
+use std::str::FromStr;
+
+use crate::error::{parse_field, ParseError};
+
+/// A line template like `"move {} from {} to {}"`: literal text with a `{}` placeholder for each
+/// field to extract. Whitespace around the line and the template is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pattern<'p> {
+    template: &'p str,
+}
+
+impl<'p> Pattern<'p> {
+    pub const fn new(template: &'p str) -> Self {
+        Self { template }
+    }
+
+    /// Matches `text`, the `line`th line of the input, pointing at the first literal that isn't
+    /// where the template says.
+    pub fn capture<'a>(&self, line: usize, text: &'a str) -> Result<Captures<'a>, ParseError> {
+        let expected = |at: &'a str, literal: &str| {
+            ParseError::at(line, text, at, format!("'{}'", literal.trim()))
+        };
+
+        let mut literals = self.template.trim().split("{}");
+        let first = literals.next().unwrap_or_default();
+        let mut rest = text
+            .trim()
+            .strip_prefix(first)
+            .ok_or_else(|| expected(text.trim(), first))?;
+
+        let mut fields = vec![];
+        for literal in literals {
+            // A placeholder at the very end takes the rest of the line.
+            let end = match literal {
+                "" => rest.len(),
+                _ => rest.find(literal).ok_or_else(|| expected(rest, literal))?,
+            };
+            fields.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+        if !rest.is_empty() {
+            return Err(ParseError::at(line, text, rest, "the end of the line"));
+        }
+
+        Ok(Captures { line, text, fields })
+    }
+
+    /// Matches the first of `patterns` that fits, or reports the error of the one that got the
+    /// furthest.
+    pub fn capture_any<'a>(
+        patterns: &[Pattern],
+        line: usize,
+        text: &'a str,
+    ) -> Result<Captures<'a>, ParseError> {
+        let mut furthest: Option<ParseError> = None;
+        for pattern in patterns {
+            match pattern.capture(line, text) {
+                Ok(captures) => return Ok(captures),
+                Err(e) if furthest.as_ref().is_some_and(|f| f.column >= e.column) => (),
+                Err(e) => furthest = Some(e),
+            }
+        }
+        Err(furthest.unwrap_or_else(|| ParseError::at(line, text, text, "a known line")))
+    }
+
+    /// The error for a line that should match but isn't there at all.
+    pub fn missing(&self) -> ParseError {
+        ParseError::missing(format!("'{}'", self.template.trim()))
+    }
+}
+
+/// The fields a `Pattern` matched, by placeholder index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'a> {
+    line: usize,
+    text: &'a str,
+    fields: Vec<&'a str>,
+}
+
+impl<'a> Captures<'a> {
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// The trimmed text of a field.
+    pub fn get(&self, index: usize) -> &'a str {
+        self.fields[index].trim()
+    }
+
+    pub fn parse<F: FromStr>(&self, index: usize, expected: &str) -> Result<F, ParseError> {
+        parse_field(self.line, self.text, self.fields[index], expected)
+    }
+
+    /// A name made of letters, digits and underscores, like a valve or a monkey.
+    pub fn ident(&self, index: usize, expected: &str) -> Result<&'a str, ParseError> {
+        let field = self.get(index);
+        match !field.is_empty() && field.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            true => Ok(field),
+            false => Err(self.error_at(field, expected)),
+        }
+    }
+
+    /// A field holding a list, like `79, 98`, with every item parsed.
+    pub fn list<F: FromStr>(
+        &self,
+        index: usize,
+        separator: char,
+        expected: &str,
+    ) -> Result<Vec<F>, ParseError> {
+        self.fields[index]
+            .split(separator)
+            .map(|item| parse_field(self.line, self.text, item, expected))
+            .collect()
+    }
+
+    /// The trimmed items of a field holding a list, like `DD, II, BB`.
+    pub fn items(&self, index: usize, separator: char) -> impl Iterator<Item = &'a str> {
+        self.fields[index].split(separator).map(str::trim)
+    }
+
+    /// Points at a field that matched but holds something the caller can't use.
+    pub fn error(&self, index: usize, expected: &str) -> ParseError {
+        self.error_at(self.get(index), expected)
+    }
+
+    /// Points at `fragment`, which must be a slice of one of the fields.
+    pub fn error_at(&self, fragment: &str, expected: &str) -> ParseError {
+        ParseError::at(self.line, self.text, fragment, expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_captures() {
+        let reading = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+        let fields = reading
+            .capture(1, "Sensor at x=2, y=18: closest beacon is at x=-2, y=15")
+            .unwrap();
+        assert_eq!(fields.len(), 4);
+        assert_eq!(fields.parse::<i32>(2, "a coordinate"), Ok(-2));
+        let e = reading.capture(1, "Sensor at x=2 y=18").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (13, "', y='"));
+
+        let items = Pattern::new("  Starting items: {}");
+        let fields = items.capture(2, "  Starting items: 79, 98").unwrap();
+        assert_eq!(fields.list(0, ',', "a worry level"), Ok(vec![79, 98]));
+        let e = Pattern::new("Monkey {}:")
+            .capture(3, "Monkey 0: 1")
+            .unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (10, "the end of the line"));
+
+        let valve = [
+            Pattern::new("Valve {} has flow rate={}; tunnels lead to valves {}"),
+            Pattern::new("Valve {} has flow rate={}; tunnel leads to valve {}"),
+        ];
+        let fields = Pattern::capture_any(
+            &valve,
+            4,
+            "Valve B-B has flow rate=13; tunnel leads to valve CC",
+        )
+        .unwrap();
+        assert_eq!(fields.items(2, ',').collect::<Vec<_>>(), ["CC"]);
+        assert_eq!(fields.ident(0, "a valve name").unwrap_err().column, 7);
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    any::Any,
+    io::{BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{inputs, Answer, Config, Error, Problem, DEFAULT_YEAR};
+
+type ParseFn = fn(BufReader<Box<dyn Read>>) -> Result<Box<dyn Any>, Error>;
+type SolveFn = fn(&dyn Any, &Config) -> Result<Answer, Error>;
+
+/// A single puzzle part and the `Problem` implementation that solves it.
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub name: &'static str,
+    parse: ParseFn,
+    solve: SolveFn,
+}
+
+fn parse_any<P>(reader: BufReader<Box<dyn Read>>) -> Result<Box<dyn Any>, Error>
+where
+    P: Problem,
+    P::Input: 'static,
+{
+    Ok(Box::new(P::parse(
+        inputs::Text::read(reader)?.into_reader(),
+    )?))
+}
+
+fn solve_any<P, const N: u32>(input: &dyn Any, config: &Config) -> Result<Answer, Error>
+where
+    P: Problem,
+    P::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<P::Input>()
+        .expect("Input parsed by a different solver.");
+    match N {
+        1 => P::part1(input, config),
+        _ => P::part2(input, config),
+    }
+}
+
+impl Solver {
+    pub(crate) const fn new<P>(year: u32, day: u32, part: u32, name: &'static str) -> Self
+    where
+        P: Problem,
+        P::Input: 'static,
+    {
+        Self {
+            year,
+            day,
+            part,
+            name,
+            parse: parse_any::<P>,
+            solve: if part == 1 {
+                solve_any::<P, 1>
+            } else {
+                solve_any::<P, 2>
+            },
+        }
+    }
+
+    /// The default input, found in the year's inputs directory by convention.
+    pub fn input(&self) -> PathBuf {
+        inputs::path(self.year, self.day, None)
+    }
+
+    /// Normalizes and parses the input into whatever this solver's `Problem` expects, see
+    /// `solve_parsed`.
+    pub fn parse(&self, reader: BufReader<Box<dyn Read>>) -> Result<Box<dyn Any>, Error> {
+        (self.parse)(reader)
+    }
+
+    /// Solves an input returned by `parse` of a solver for the same day.
+    pub fn solve_parsed(&self, input: &dyn Any, config: &Config) -> Result<Answer, Error> {
+        (self.solve)(input, config)
+    }
+
+    pub fn solve_buffer(
+        &self,
+        reader: BufReader<Box<dyn Read>>,
+        config: &Config,
+    ) -> Result<Answer, Error> {
+        self.solve_parsed(self.parse(reader)?.as_ref(), config)
+    }
+
+    /// Solves an input file, or stdin for `-`.
+    pub fn solve_file<P: AsRef<Path>>(&self, path: P, config: &Config) -> Result<Answer, Error> {
+        self.solve_buffer(inputs::open(path)?, config)
+    }
+}
+
+/// The solvers of every year, oldest first. A year is a module like `y2022` with its days and
+/// their `SOLVERS`.
+#[rustfmt::skip]
+const YEARS: &[&[Solver]] = &[
+    crate::y2022::SOLVERS,
+];
+
+pub fn solvers() -> impl Iterator<Item = &'static Solver> {
+    YEARS.iter().flat_map(|solvers| solvers.iter())
+}
+
+/// The most recent year with solvers, the default for the commands.
+pub fn latest_year() -> u32 {
+    solvers().map(|s| s.year).max().unwrap_or(DEFAULT_YEAR)
+}
+
+pub fn find(year: u32, day: u32, part: u32) -> Option<&'static Solver> {
+    solvers().find(|s| s.year == year && s.day == day && s.part == part)
+}
+
+/// Solvers for the given year, day and part, or for all of them when left out.
+pub fn select(
+    year: Option<u32>,
+    day: Option<u32>,
+    part: Option<u32>,
+) -> impl Iterator<Item = &'static Solver> {
+    solvers().filter(move |s| {
+        year.unwrap_or(s.year) == s.year
+            && day.unwrap_or(s.day) == s.day
+            && part.unwrap_or(s.part) == s.part
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{inputs, Answer, Config};
+
+    #[test]
+    fn registry_find() {
+        let solver = find(2022, 6, 2).unwrap();
+        let config = Config::new();
+        assert_eq!(
+            solver.solve_file(solver.input(), &config).unwrap(),
+            Answer::Int(2803)
+        );
+        assert!(solver.solve_file("", &config).is_err());
+        assert!(find(2022, 24, 2).is_none());
+
+        let input = find(2022, 6, 1)
+            .unwrap()
+            .parse(inputs::open(inputs::path(2022, 6, None)).unwrap())
+            .unwrap();
+        assert_eq!(
+            solver.solve_parsed(input.as_ref(), &config).unwrap(),
+            Answer::Int(2803)
+        );
+    }
+
+    #[test]
+    fn registry_is_well_formed() {
+        let solvers = solvers().collect::<Vec<_>>();
+        for (index, solver) in solvers.iter().enumerate() {
+            assert!(solver.input().exists());
+            assert!(solvers[..index]
+                .iter()
+                .all(|other| (other.year, other.day, other.part)
+                    != (solver.year, solver.day, solver.part)));
+        }
+        assert_eq!(latest_year(), 2022);
+        assert_eq!(select(Some(2021), None, None).count(), 0);
+    }
+}
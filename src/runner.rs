@@ -0,0 +1,137 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{cancel::Cancel, registry::Solver, Answer, Config, Error};
+
+pub enum Status {
+    Solved(Answer),
+    Failed(Error),
+    /// The solver panicked, with the panic message.
+    Panicked(String),
+}
+
+pub struct Run {
+    pub solver: &'static Solver,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+impl Run {
+    pub fn is_solved(&self) -> bool {
+        matches!(self.status, Status::Solved(_))
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".into(),
+        },
+    }
+}
+
+/// Solves every solver's default input on `jobs` threads, sorted by year, day and part. A
+/// solver that panics is reported as such without stopping the others, one still running after
+/// `timeout` is told to give up.
+pub fn run_all(
+    solvers: &[&'static Solver],
+    config: &Config,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Run> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(vec![]);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, solvers.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&solver) = solvers.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let mut cancel = Cancel::new();
+                    if let Some(timeout) = timeout {
+                        cancel = cancel.with_timeout(timeout);
+                    }
+                    let config = config.clone().with_cancel(cancel);
+                    let t0 = Instant::now();
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                        solver.solve_file(solver.input(), &config)
+                    }));
+                    let elapsed = t0.elapsed();
+                    let status = match result {
+                        Ok(Ok(answer)) => Status::Solved(answer),
+                        Ok(Err(e)) => Status::Failed(e),
+                        Err(payload) => Status::Panicked(panic_message(payload)),
+                    };
+                    runs.lock().unwrap().push(Run {
+                        solver,
+                        status,
+                        elapsed,
+                    });
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| (run.solver.year, run.solver.day, run.solver.part));
+    runs
+}
+
+pub fn print_table(runs: &[Run]) {
+    println!(
+        "{:>4} {:>3} {:>4}  {:<26} {:>12}  {:<6}  answer",
+        "year", "day", "part", "name", "time", "status"
+    );
+    for run in runs {
+        let (status, detail) = match &run.status {
+            Status::Solved(answer) => ("ok", answer.to_string()),
+            Status::Failed(Error::TimedOut) => ("TIMEOUT", String::new()),
+            Status::Failed(e) => ("error", e.to_string()),
+            Status::Panicked(message) => ("PANIC", message.clone()),
+        };
+        // Multi-line answers like Day 10's CRT continue under the answer column.
+        let indent = format!("\n{:64}", "");
+        println!(
+            "{:>4} {:>3} {:>4}  {:<26} {:>12}  {:<6}  {}",
+            run.solver.year,
+            run.solver.day,
+            run.solver.part,
+            run.solver.name,
+            format!("{:?}", run.elapsed),
+            status,
+            detail.replace('\n', &indent)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{registry, Answer::Int, Config, Error};
+
+    #[test]
+    fn run_all_in_parallel() {
+        let solvers =
+            [(15, 1), (6, 2), (6, 1)].map(|(day, part)| registry::find(2022, day, part).unwrap());
+        let runs = run_all(&solvers, &Config::new().with("row", "ten"), 2, None);
+        let order = runs
+            .iter()
+            .map(|r| (r.solver.day, r.solver.part))
+            .collect::<Vec<_>>();
+        assert_eq!(order, [(6, 1), (6, 2), (15, 1)]);
+        assert!(matches!(runs[1].status, Status::Solved(Int(2803))));
+        assert!(matches!(
+            runs[2].status,
+            Status::Failed(Error::Parameter { .. })
+        ));
+    }
+}
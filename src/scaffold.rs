@@ -0,0 +1,282 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::{inputs, verify, Error, DEFAULT_YEAR};
+
+const TEMPLATE: &str = include_str!("day_template.rs");
+
+/// `Day<day>`, with the year only when it isn't the default one.
+fn day_type(year: u32, day: u32) -> String {
+    match year {
+        DEFAULT_YEAR => format!("Day<{}>", day),
+        _ => format!("Day<{}, {}>", day, year),
+    }
+}
+
+/// The source of a new day, `day_template.rs` solving the new day instead of `Day<0>`.
+pub fn module(year: u32, day: u32) -> String {
+    TEMPLATE.replace("Day<0>", &day_type(year, day))
+}
+
+fn solver_entry(year: u32, day: u32, name: &str) -> String {
+    format!(
+        "    Solver::new::<{}>(YEAR, {}, 1, {:?}),",
+        day_type(year, day),
+        day,
+        name
+    )
+}
+
+/// The `mod.rs` of a year that doesn't have one yet, with `day` as its only day.
+pub fn year_module(year: u32, day: u32, name: &str) -> String {
+    format!(
+        "use crate::{{registry::Solver, Day}};\n\
+         \n\
+         pub mod day{};\n\
+         \n\
+         pub const YEAR: u32 = {};\n\
+         \n\
+         #[rustfmt::skip]\n\
+         pub const SOLVERS: &[Solver] = &[\n\
+         {}\n\
+         ];\n",
+        day,
+        year,
+        solver_entry(year, day, name)
+    )
+}
+
+fn insert_line(text: &str, index: usize, line: &str) -> String {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    lines.insert(index, line);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+/// The number right after `prefix` in a line like `Solver::new::<Day<12>>(...)`.
+fn leading_number(line: &str, prefix: &str) -> Option<u32> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    rest[..digits].parse().ok()
+}
+
+/// Declares `pub mod <name>;` in `lib.rs` or a year's `mod.rs`, keeping the modules sorted.
+/// `None` when it already is declared or there are no modules to go by.
+pub fn declare_module(source: &str, name: &str) -> Option<String> {
+    let declarations = source
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let line = line.strip_prefix("// ").unwrap_or(line);
+            let line = line.strip_prefix("pub ").unwrap_or(line);
+            Some((index, line.strip_prefix("mod ")?.strip_suffix(';')?))
+        })
+        .collect::<Vec<_>>();
+
+    if declarations.iter().any(|&(_, module)| module == name) {
+        return None;
+    }
+    let index = match declarations.iter().find(|&&(_, module)| module > name) {
+        Some(&(index, _)) => index,
+        None => declarations.last()?.0 + 1,
+    };
+    Some(insert_line(source, index, &format!("pub mod {};", name)))
+}
+
+/// Adds part 1 of `day` to the `SOLVERS` of a year's `mod.rs`, in day order. `None` when the day
+/// already has a solver or there is no list to add to.
+pub fn register_solver(year_module: &str, year: u32, day: u32, name: &str) -> Option<String> {
+    let entries = year_module
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let line = line.trim_start().strip_prefix("Solver::new::<Day<")?;
+            Some((index, leading_number(line, "")?))
+        })
+        .collect::<Vec<_>>();
+
+    if entries.iter().any(|&(_, d)| d == day) {
+        return None;
+    }
+    let index = match entries.iter().find(|&&(_, d)| d > day) {
+        Some(&(index, _)) => index,
+        None => entries.last()?.0 + 1,
+    };
+    Some(insert_line(
+        year_module,
+        index,
+        &solver_entry(year, day, name),
+    ))
+}
+
+/// Adds a year's `SOLVERS` to `YEARS` in `registry.rs`, in year order. `None` when it already is
+/// there or there is no list to add to.
+pub fn register_year(registry: &str, year: u32) -> Option<String> {
+    let lines = registry.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("const YEARS"))?;
+    let end = start + lines[start..].iter().position(|&line| line == "];")?;
+
+    let years = (start + 1..end)
+        .filter_map(|index| Some((index, leading_number(lines[index], "crate::y")?)))
+        .collect::<Vec<_>>();
+    if years.iter().any(|&(_, y)| y == year) {
+        return None;
+    }
+    let index = years
+        .iter()
+        .find(|&&(_, y)| y > year)
+        .map_or(end, |&(index, _)| index);
+    Some(insert_line(
+        registry,
+        index,
+        &format!("    crate::y{}::SOLVERS,", year),
+    ))
+}
+
+/// Adds part 1 of `day` on its usual input to the answers file, in year and day order. Its
+/// answer is a placeholder, so the known answers test fails until the real one is filled in.
+/// `None` when the day already has one.
+pub fn add_answer(answers: &str, year: u32, day: u32) -> Option<String> {
+    let input = inputs::file_name(day, None);
+    let rows = answers
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let key = (
+                leading_number(fields.first()?, "")?,
+                leading_number(fields.get(1)?, "")?,
+            );
+            Some((
+                index,
+                key,
+                fields.get(2) == Some(&"1") && fields.get(3) == Some(&&input[..]),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    if rows
+        .iter()
+        .any(|&(_, key, same)| key == (year, day) && same)
+    {
+        return None;
+    }
+    let index = match rows.iter().find(|&&(_, key, _)| key > (year, day)) {
+        Some(&(index, _, _)) => index,
+        None => answers.lines().count(),
+    };
+    Some(insert_line(
+        answers,
+        index,
+        &format!("{}\t{}\t1\t{}\tTODO", year, day, input),
+    ))
+}
+
+fn conflict(message: String) -> Error {
+    io::Error::new(ErrorKind::AlreadyExists, message).into()
+}
+
+/// Starts a new day in the crate at `root`: the module from the template, its registration and
+/// known answer, and an empty input. A year without days yet gets its module tree too. Nothing is
+/// written if the day already exists. Returns the files written.
+pub fn new_day(root: &Path, year: u32, day: u32, name: &str) -> Result<Vec<PathBuf>, Error> {
+    let year_dir = root.join("src").join(format!("y{}", year));
+    let year_path = year_dir.join("mod.rs");
+    let module_path = year_dir.join(format!("day{}.rs", day));
+    let lib_path = root.join("src/lib.rs");
+    let answers_path = root.join(verify::ANSWERS_FILE);
+    let registry_path = root.join("src/registry.rs");
+    let input_path = root.join(inputs::path(year, day, None));
+    let already_in =
+        |path: &Path| conflict(format!("day {} is already in {}", day, path.display()));
+
+    if module_path.exists() {
+        return Err(conflict(format!(
+            "{} already exists",
+            module_path.display()
+        )));
+    }
+    let answers = fs::read_to_string(&answers_path)?;
+    let answers = add_answer(&answers, year, day).ok_or_else(|| already_in(&answers_path))?;
+
+    let mut written = vec![module_path.clone(), year_path.clone(), answers_path.clone()];
+    let mut lib = None;
+    let (year_source, registry) = if year_path.exists() {
+        let source = fs::read_to_string(&year_path)?;
+        let source = declare_module(&source, &format!("day{}", day))
+            .and_then(|source| register_solver(&source, year, day, name))
+            .ok_or_else(|| already_in(&year_path))?;
+        (source, None)
+    } else {
+        let year_module_name = format!("y{}", year);
+        let source = fs::read_to_string(&lib_path)?;
+        let source =
+            declare_module(&source, &year_module_name).ok_or_else(|| already_in(&lib_path))?;
+        lib = Some(source);
+        let registry = fs::read_to_string(&registry_path)?;
+        let registry = register_year(&registry, year).ok_or_else(|| already_in(&registry_path))?;
+        written.push(lib_path.clone());
+        written.push(registry_path.clone());
+        (year_module(year, day, name), Some(registry))
+    };
+
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&module_path, module(year, day))?;
+    fs::write(&year_path, year_source)?;
+    fs::write(&answers_path, answers)?;
+    if let Some(lib) = lib {
+        fs::write(&lib_path, lib)?;
+    }
+    if let Some(registry) = registry {
+        fs::write(&registry_path, registry)?;
+    }
+    if !input_path.exists() {
+        if let Some(dir) = input_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&input_path, "")?;
+        written.push(input_path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaffold_new_day() {
+        assert!(module(2022, 25).contains("impl Problem for Day<25> {"));
+        assert!(module(2023, 1).contains("impl Problem for Day<1, 2023> {"));
+
+        let year = include_str!("y2022/mod.rs");
+        let year = declare_module(year, "day25").unwrap();
+        assert!(year.contains("pub mod day24;\npub mod day25;\npub mod day3;\n"));
+        assert_eq!(declare_module(&year, "day25"), None);
+        let year = register_solver(&year, 2022, 25, "Full of Hot Air").unwrap();
+        assert!(year.contains(
+            "(YEAR, 24, 1, \"Blizzard Basin\"),\n    Solver::new::<Day<25>>(YEAR, 25, 1, \"Full of Hot Air\"),\n];"
+        ));
+        assert_eq!(register_solver(&year, 2022, 5, "Supply Stacks"), None);
+
+        let answers = add_answer(include_str!("../answers.txt"), 2022, 25).unwrap();
+        let answers = add_answer(&answers, 2023, 1).unwrap();
+        assert!(answers.ends_with(concat!(
+            "2022\t23\t2\tin23.txt\t1069\n",
+            "2022\t25\t1\tin25.txt\tTODO\n",
+            "2023\t1\t1\tin1.txt\tTODO\n",
+        )));
+        assert_eq!(add_answer(&answers, 2023, 1), None);
+        assert_eq!(add_answer(&answers, 2022, 9), None);
+
+        let registry = register_year(include_str!("registry.rs"), 2023).unwrap();
+        assert!(registry.contains("    crate::y2022::SOLVERS,\n    crate::y2023::SOLVERS,\n];"));
+        assert_eq!(register_year(&registry, 2022), None);
+    }
+}
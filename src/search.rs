@@ -0,0 +1,336 @@
+use std::{
+    cell::{Cell, RefCell},
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+use lk_math::{
+    explore::{Exploration, ExploreSignals, PointKeyValue},
+    geometric_traits::IterateNeighbours,
+    sketch::Bag,
+};
+
+/// What one exploration did, counted from the closures it was given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States the visit closure let the search go on from.
+    pub expanded: u64,
+    /// States the visit closure pruned with `ExploreSignals::Skip`.
+    pub skipped: u64,
+    /// Neighbours the filter closure let through.
+    pub pushed: u64,
+    /// Neighbours let through but never visited, because the exploration found them identical
+    /// to or worse than a state it had seen, or because the search stopped first.
+    pub dropped: u64,
+    /// Neighbours `explore_avoid_worse` let through while a state with the same key let through
+    /// before was at least as good, by `PointKeyValue::compare_values`. Always 0 for the other
+    /// searches.
+    pub dominated: u64,
+    /// An upper bound of the most states waiting to be visited at any one time. The closures
+    /// never see the exploration drop a state, so one it drops keeps counting as waiting until
+    /// the search ends.
+    pub peak_frontier: u64,
+    pub elapsed: Duration,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} expanded, {} skipped, {} pushed, {} dropped, {} dominated, peak frontier at most {} in {:?}",
+            self.expanded,
+            self.skipped,
+            self.pushed,
+            self.dropped,
+            self.dominated,
+            self.peak_frontier,
+            self.elapsed
+        )
+    }
+}
+
+/// The counts behind `Stats`, in cells as both closures need them at once.
+struct Counter {
+    start: Instant,
+    visited: Cell<u64>,
+    expanded: Cell<u64>,
+    skipped: Cell<u64>,
+    pushed: Cell<u64>,
+    dominated: Cell<u64>,
+    peak_frontier: Cell<u64>,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            visited: Cell::new(0),
+            expanded: Cell::new(0),
+            skipped: Cell::new(0),
+            pushed: Cell::new(0),
+            dominated: Cell::new(0),
+            // The frontier starts out with the start state.
+            peak_frontier: Cell::new(1),
+        }
+    }
+
+    fn frontier(&self) -> u64 {
+        (1 + self.pushed.get()).saturating_sub(self.visited.get())
+    }
+
+    fn visit<'a, P, C, D>(
+        &'a self,
+        mut f: impl FnMut(&P, &mut C, &mut D) -> ExploreSignals + 'a,
+    ) -> impl FnMut(&P, &mut C, &mut D) -> ExploreSignals + 'a {
+        move |p, context, data| {
+            self.visited.set(self.visited.get() + 1);
+            let signal = f(p, context, data);
+            let count = match signal {
+                ExploreSignals::Explore => &self.expanded,
+                ExploreSignals::Skip => &self.skipped,
+                ExploreSignals::ReachedGoal => return signal,
+            };
+            count.set(count.get() + 1);
+            signal
+        }
+    }
+
+    fn filter<'a, P, C, D>(
+        &'a self,
+        mut g: impl FnMut(&P, &P, &mut C, &mut D) -> bool + 'a,
+    ) -> impl FnMut(&P, &P, &mut C, &mut D) -> bool + 'a {
+        move |p, n, context, data| {
+            let pass = g(p, n, context, data);
+            if pass {
+                self.pushed.set(self.pushed.get() + 1);
+                let peak = self.peak_frontier.get().max(self.frontier());
+                self.peak_frontier.set(peak);
+            }
+            pass
+        }
+    }
+
+    /// `filter` for `explore_avoid_worse`, also counting the neighbours no better than one let
+    /// through before. `best` holds the values of those that were, by key.
+    fn filter_dominated<'a, P, C, D>(
+        &'a self,
+        best: &'a RefCell<HashMap<P::K, Vec<P::V>>>,
+        g: impl FnMut(&P, &P, &mut C, &mut D) -> bool + 'a,
+    ) -> impl FnMut(&P, &P, &mut C, &mut D) -> bool + 'a
+    where
+        P: PointKeyValue,
+    {
+        let mut g = self.filter(g);
+        move |p, n, context, data| {
+            let pass = g(p, n, context, data);
+            if pass && !improves(&mut best.borrow_mut(), n) {
+                self.dominated.set(self.dominated.get() + 1);
+            }
+            pass
+        }
+    }
+
+    fn stats(&self) -> Stats {
+        Stats {
+            expanded: self.expanded.get(),
+            skipped: self.skipped.get(),
+            pushed: self.pushed.get(),
+            dropped: self.frontier(),
+            dominated: self.dominated.get(),
+            peak_frontier: self.peak_frontier.get(),
+            elapsed: self.start.elapsed(),
+        }
+    }
+}
+
+/// Records the value of `state` unless one recorded for its key is at least as good, which
+/// `compare_values` tells by ordering the new value less. Values it beats are forgotten.
+fn improves<P: PointKeyValue>(best: &mut HashMap<P::K, Vec<P::V>>, state: &P) -> bool {
+    let key = state.get_key();
+    let worse = |a: &P::V, b: &P::V| P::compare_values(&key, a, b) == Some(Ordering::Less);
+    let value = state.get_value();
+    let values = best.entry(state.get_key()).or_default();
+    if values.iter().any(|seen| worse(&value, seen)) {
+        return false;
+    }
+    values.retain(|seen| !worse(seen, &value));
+    values.push(value);
+    true
+}
+
+/// The searches of `Exploration`, counting what they do. Days log the stats at info level, so
+/// `-v` shows how hard each search worked.
+pub trait CountedExploration<C, D> {
+    fn explore_counted<P, F, B>(
+        &mut self,
+        start: P,
+        f: F,
+        g: impl FnMut(&P, &P, &mut C, &mut D) -> bool,
+    ) -> Stats
+    where
+        P: IterateNeighbours<C> + Clone,
+        F: FnMut(&P, &mut C, &mut D) -> ExploreSignals,
+        B: Bag<P>;
+
+    fn explore_avoid_identical_counted<P, F, B>(
+        &mut self,
+        start: P,
+        f: F,
+        g: impl FnMut(&P, &P, &mut C, &mut D) -> bool,
+    ) -> Stats
+    where
+        P: IterateNeighbours<C> + Clone + Hash + Eq,
+        F: FnMut(&P, &mut C, &mut D) -> ExploreSignals,
+        B: Bag<P>;
+
+    fn explore_avoid_worse_counted<P, F, B>(
+        &mut self,
+        start: P,
+        f: F,
+        g: impl FnMut(&P, &P, &mut C, &mut D) -> bool,
+    ) -> Stats
+    where
+        P: IterateNeighbours<C> + Clone + PointKeyValue,
+        F: FnMut(&P, &mut C, &mut D) -> ExploreSignals,
+        B: Bag<P>;
+}
+
+impl<C, D> CountedExploration<C, D> for Exploration<C, D> {
+    fn explore_counted<P, F, B>(
+        &mut self,
+        start: P,
+        f: F,
+        g: impl FnMut(&P, &P, &mut C, &mut D) -> bool,
+    ) -> Stats
+    where
+        P: IterateNeighbours<C> + Clone,
+        F: FnMut(&P, &mut C, &mut D) -> ExploreSignals,
+        B: Bag<P>,
+    {
+        let counter = Counter::new();
+        self.explore::<_, _, B>(start, counter.visit(f), counter.filter(g));
+        counter.stats()
+    }
+
+    fn explore_avoid_identical_counted<P, F, B>(
+        &mut self,
+        start: P,
+        f: F,
+        g: impl FnMut(&P, &P, &mut C, &mut D) -> bool,
+    ) -> Stats
+    where
+        P: IterateNeighbours<C> + Clone + Hash + Eq,
+        F: FnMut(&P, &mut C, &mut D) -> ExploreSignals,
+        B: Bag<P>,
+    {
+        let counter = Counter::new();
+        self.explore_avoid_identical::<_, _, B>(start, counter.visit(f), counter.filter(g));
+        counter.stats()
+    }
+
+    fn explore_avoid_worse_counted<P, F, B>(
+        &mut self,
+        start: P,
+        f: F,
+        g: impl FnMut(&P, &P, &mut C, &mut D) -> bool,
+    ) -> Stats
+    where
+        P: IterateNeighbours<C> + Clone + PointKeyValue,
+        F: FnMut(&P, &mut C, &mut D) -> ExploreSignals,
+        B: Bag<P>,
+    {
+        let counter = Counter::new();
+        let best = RefCell::new(HashMap::new());
+        improves(&mut best.borrow_mut(), &start);
+        self.explore_avoid_worse::<_, _, B>(
+            start,
+            counter.visit(f),
+            counter.filter_dominated(&best, g),
+        );
+        counter.stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lk_math::sketch::QueueBag;
+
+    /// A node reached at some cost, the edges out of every node being the context.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct Step {
+        node: usize,
+        cost: u32,
+    }
+
+    type Edges = Vec<Vec<usize>>;
+
+    impl IterateNeighbours<Edges> for Step {
+        fn neighbours(&self, edges: &Edges) -> Vec<Self> {
+            let cost = self.cost + 1;
+            edges[self.node]
+                .iter()
+                .map(|&node| Step { node, cost })
+                .collect()
+        }
+    }
+
+    impl PointKeyValue for Step {
+        type K = usize;
+        type V = u32;
+
+        fn get_key(&self) -> Self::K {
+            self.node
+        }
+
+        fn get_value(&self) -> Self::V {
+            self.cost
+        }
+
+        fn compare_values(_k: &Self::K, a: &Self::V, b: &Self::V) -> Option<Ordering> {
+            match a >= b {
+                true => Some(Ordering::Less),
+                false => Some(Ordering::Greater),
+            }
+        }
+    }
+
+    #[test]
+    fn counts_what_the_search_did() {
+        // 3 is reached in 2 steps, and again in 5 the long way round through 4 and 5. The
+        // filter keeps 7 out and the search skips 6.
+        let edges = vec![
+            vec![1, 2],
+            vec![3, 7],
+            vec![4, 6],
+            vec![],
+            vec![5],
+            vec![3],
+            vec![],
+            vec![],
+        ];
+        let mut exploration = Exploration::new(edges, ());
+        let stats = exploration.explore_avoid_worse_counted::<_, _, QueueBag<_>>(
+            Step { node: 0, cost: 0 },
+            |p, _, _| match p.node {
+                6 => ExploreSignals::Skip,
+                _ => ExploreSignals::Explore,
+            },
+            |_, n, _, _| n.node != 7,
+        );
+        assert_eq!(
+            (
+                stats.expanded,
+                stats.skipped,
+                stats.pushed,
+                stats.dropped,
+                stats.dominated,
+                stats.peak_frontier
+            ),
+            (6, 1, 7, 1, 1, 3)
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use std::convert::Infallible;
+
+/// Something that moves on in discrete steps, like falling rocks or wandering elves.
+///
+/// Two states with equal fingerprints must go on the same way, which is what lets `run_n` spot
+/// a cycle and skip over it instead of stepping through a trillion rocks. Simulations without a
+/// fingerprint are just stepped through.
+pub trait Simulation: Clone {
+    /// `()` for simulations without a fingerprint.
+    type Fingerprint: PartialEq;
+
+    fn step(&mut self);
+
+    /// `None` turns cycle detection off, for simulations that never repeat or whose states are
+    /// too big to compare every step.
+    fn fingerprint(&self) -> Option<Self::Fingerprint> {
+        None
+    }
+
+    /// Skips `cycles` more turns of the cycle that led from `earlier` to the current state. Both
+    /// have the same fingerprint, so only what it leaves out needs to move on, like the height of
+    /// the tower. Nothing by default, for simulations whose fingerprint is the whole state.
+    fn fast_forward(&mut self, _earlier: &Self, _cycles: u64) {}
+
+    /// Takes `n` steps, or far fewer once the states start repeating.
+    fn run_n(&mut self, n: u64) {
+        self.run_n_with(n, |_| ())
+    }
+
+    /// `run_n`, showing `inspect` every state a step is taken from. Skipped cycles aren't shown.
+    fn run_n_with(&mut self, n: u64, mut inspect: impl FnMut(&Self)) {
+        let result = self.try_run_n_with(n, |state| -> Result<(), Infallible> {
+            inspect(state);
+            Ok(())
+        });
+        result.unwrap_or_else(|never| match never {})
+    }
+
+    /// `run_n_with`, stopping at the first error `inspect` returns, like a `Ticker` that fired.
+    fn try_run_n_with<E>(
+        &mut self,
+        n: u64,
+        mut inspect: impl FnMut(&Self) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut brent = Brent::new(self);
+        for taken in 1..=n {
+            inspect(self)?;
+            self.step();
+            if let Some(length) = brent.as_mut().and_then(|b| b.observe(self)) {
+                let left = n - taken;
+                let earlier = brent.unwrap().snapshot;
+                self.fast_forward(&earlier, left / length);
+                for _ in 0..left % length {
+                    inspect(self)?;
+                    self.step();
+                }
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    /// Steps until `done` holds, returning the number of steps taken. `None` when the simulation
+    /// goes round in a cycle instead, so `done` should only look at what the fingerprint covers.
+    /// `done` sees every state once.
+    fn run_until(&mut self, mut done: impl FnMut(&Self) -> bool) -> Option<u64> {
+        let mut brent = Brent::new(self);
+        let mut taken = 0;
+        while !done(self) {
+            if taken > 0 && brent.as_mut().and_then(|b| b.observe(self)).is_some() {
+                return None;
+            }
+            self.step();
+            taken += 1;
+        }
+        Some(taken)
+    }
+}
+
+/// Brent's cycle detection: compares every state with a snapshot that is moved up to the
+/// current state whenever the number of steps since it reaches the next power of two.
+struct Brent<S: Simulation> {
+    snapshot: S,
+    fingerprint: S::Fingerprint,
+    power: u64,
+    length: u64,
+}
+
+impl<S: Simulation> Brent<S> {
+    /// `None` for simulations without a fingerprint.
+    fn new(simulation: &S) -> Option<Self> {
+        Some(Self {
+            fingerprint: simulation.fingerprint()?,
+            snapshot: simulation.clone(),
+            power: 1,
+            length: 0,
+        })
+    }
+
+    /// Call after every step. The length of the cycle once the simulation is back at the state
+    /// of the snapshot, which is then that many steps behind. States without a fingerprint are
+    /// stepped over, the snapshot moves up to the next one that has it.
+    fn observe(&mut self, simulation: &S) -> Option<u64> {
+        self.length += 1;
+        let fingerprint = simulation.fingerprint()?;
+        if fingerprint == self.fingerprint {
+            return Some(self.length);
+        }
+        if self.length >= self.power {
+            self.snapshot = simulation.clone();
+            self.fingerprint = fingerprint;
+            self.power *= 2;
+            self.length = 0;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulation_cycles() {
+        /// Counts up mod 7, with how far it got in total left out of the fingerprint.
+        #[derive(Clone)]
+        struct Counter {
+            at: u64,
+            total: u64,
+        }
+        impl Simulation for Counter {
+            type Fingerprint = u64;
+
+            fn step(&mut self) {
+                self.at = (self.at + 1) % 7;
+                self.total += 1;
+            }
+
+            fn fingerprint(&self) -> Option<u64> {
+                Some(self.at)
+            }
+
+            fn fast_forward(&mut self, earlier: &Self, cycles: u64) {
+                self.total += (self.total - earlier.total) * cycles;
+            }
+        }
+
+        let mut counter = Counter { at: 3, total: 0 };
+        counter.run_n(1_000_000_000_000);
+        assert_eq!(
+            (counter.at, counter.total),
+            (1_000_000_000_003 % 7, 1_000_000_000_000)
+        );
+
+        let mut seen = vec![];
+        let mut counter = Counter { at: 0, total: 0 };
+        counter.run_n_with(3, |c| seen.push(c.at));
+        assert_eq!(seen, [0, 1, 2]);
+
+        let mut counter = Counter { at: 0, total: 0 };
+        assert_eq!(counter.run_until(|c| c.at == 5), Some(5));
+        assert_eq!(counter.run_until(|c| c.at == 7), None);
+    }
+}
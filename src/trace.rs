@@ -0,0 +1,215 @@
+use std::{
+    fmt::{Arguments, Display},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+use crate::error::Expected;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    /// The level enabled by `-v` repeated `count` times, starting at `Info`.
+    pub fn from_verbosity(count: usize) -> Option<Self> {
+        match count {
+            0 => None,
+            _ => Some(Self::ALL[(count + 1).min(Self::ALL.len() - 1)]),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// Which messages get written, by target (the module name, like `day22`) and level.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    /// Writes nothing.
+    pub const fn new() -> Self {
+        Self {
+            default: None,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Writes messages up to `level` from every target.
+    pub fn with_level(mut self, level: Option<Level>) -> Self {
+        self.default = level;
+        self
+    }
+
+    /// Writes messages up to `level` from `target`, whatever the default.
+    pub fn with_target<S: Into<String>>(mut self, target: S, level: Level) -> Self {
+        let target = target.into();
+        self.targets.retain(|(t, _)| t != &target);
+        self.targets.push((target, level));
+        self
+    }
+
+    /// Reads comma-separated directives, each either a level or `target=level`, like
+    /// `info,day22=trace`.
+    pub fn parse(s: &str) -> Result<Self, Expected<'_>> {
+        let mut filter = Self::new();
+        for directive in s.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            let expected = || Expected::new(directive, "a level or 'target=level'");
+            filter = match directive.split_once('=') {
+                Some((target, level)) => {
+                    filter.with_target(target.trim(), level.trim().parse().map_err(|_| expected())?)
+                }
+                None => filter.with_level(Some(directive.parse().map_err(|_| expected())?)),
+            };
+        }
+        Ok(filter)
+    }
+
+    /// Adds the directives of `other`, which take precedence.
+    pub fn merge(mut self, other: Filter) -> Self {
+        if other.default.is_some() {
+            self.default = other.default;
+        }
+        for (target, level) in other.targets {
+            self = self.with_target(target, level);
+        }
+        self
+    }
+
+    pub fn allows(&self, level: Level, target: &str) -> bool {
+        let max = self
+            .targets
+            .iter()
+            .find(|(t, _)| t == target)
+            .map(|(_, l)| Some(*l))
+            .unwrap_or(self.default);
+        max.is_some_and(|max| level <= max)
+    }
+
+    fn max_level(&self) -> u8 {
+        let targets = self.targets.iter().map(|(_, l)| *l);
+        self.default
+            .into_iter()
+            .chain(targets)
+            .max()
+            .map_or(0, |l| l as u8)
+    }
+}
+
+// Checked before taking the lock so disabled messages cost one atomic load.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new());
+
+/// Replaces the filter used by the logging macros. Nothing is written until this is called.
+pub fn init(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level(), Ordering::Relaxed);
+    *FILTER.write().unwrap() = filter;
+}
+
+/// The last component of a module path, `aoc2022::day22` becoming `day22`.
+pub fn target(module_path: &'static str) -> &'static str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed) && FILTER.read().unwrap().allows(level, target)
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: Arguments) {
+    eprintln!("{:>5} {}: {}", level, target, args);
+}
+
+/// Writes a message to stderr if the filter allows `level` for the calling module.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        let target = $crate::trace::target(module_path!());
+        if $crate::trace::enabled(level, target) {
+            $crate::trace::write(level, target, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_filter() {
+        let filter = Filter::parse("warn, day22=trace,day19=debug").unwrap();
+        assert!(filter.allows(Level::Trace, "day22"));
+        assert!(filter.allows(Level::Debug, "day19"));
+        assert!(!filter.allows(Level::Trace, "day19"));
+        assert!(filter.allows(Level::Warn, "day11"));
+        assert!(!filter.allows(Level::Info, "day11"));
+        assert!(!Filter::new().allows(Level::Error, "day1"));
+        assert!(Filter::parse("day22=loud").is_err());
+
+        assert_eq!(Level::from_verbosity(0), None);
+        assert_eq!(Level::from_verbosity(2), Some(Level::Debug));
+        assert_eq!(Level::from_verbosity(9), Some(Level::Trace));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    error::{parse_field, ParseError},
+    inputs, registry, Answer, Config, Error,
+};
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// One line of the answers file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// File name in the year's inputs directory.
+    pub input: String,
+    pub answer: String,
+    /// Puzzle parameters the answer was computed with, see `Config`.
+    pub config: Config,
+}
+
+/// Writes `answer` the way it appears in the answers file, on a single line.
+pub fn escape(answer: &Answer) -> String {
+    answer
+        .to_string()
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+}
+
+/// Reads tab-separated `year day part input answer [parameters]` lines. Blank lines and lines starting
+/// with `#` are skipped.
+pub fn parse(text: &str) -> Result<Vec<KnownAnswer>, ParseError> {
+    let mut results = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let n = index + 1;
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (fields, config) = match line.split('\t').collect::<Vec<_>>()[..] {
+            [year, day, part, input, answer] => ([year, day, part, input, answer], Config::new()),
+            [year, day, part, input, answer, params] => (
+                [year, day, part, input, answer],
+                Config::parse(params).map_err(|e| e.on_line(n, line))?,
+            ),
+            _ => return Err(ParseError::at(
+                n,
+                line,
+                line,
+                "tab-separated fields: year, day, part, input, answer and optionally parameters",
+            )),
+        };
+
+        let [year, day, part, input, answer] = fields;
+        results.push(KnownAnswer {
+            year: parse_field(n, line, year, "a year")?,
+            day: parse_field(n, line, day, "a day")?,
+            part: parse_field(n, line, part, "a part")?,
+            input: input.to_string(),
+            answer: answer.to_string(),
+            config,
+        });
+    }
+    Ok(results)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Mismatch { actual: String },
+    Fail(String),
+}
+
+pub fn check(known: &KnownAnswer) -> Verdict {
+    let solver = match registry::find(known.year, known.day, known.part) {
+        Some(solver) => solver,
+        None => return Verdict::Fail("no solver".into()),
+    };
+
+    let input = inputs::year_dir(known.year).join(&known.input);
+    match solver.solve_file(input, &known.config) {
+        Ok(answer) => {
+            let actual = escape(&answer);
+            if actual == known.answer {
+                Verdict::Pass
+            } else {
+                Verdict::Mismatch { actual }
+            }
+        }
+        Err(Error::Parse(e)) => Verdict::Fail(format!("{}: {}", known.input, e)),
+        Err(e) => Verdict::Fail(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::panic_message;
+
+    #[test]
+    fn answers_file() {
+        let known = parse(&std::fs::read_to_string(ANSWERS_FILE).unwrap()).unwrap();
+        for (index, k) in known.iter().enumerate() {
+            assert!(inputs::year_dir(k.year).join(&k.input).exists());
+            assert!(registry::find(k.year, k.day, k.part).is_some());
+            assert!(known[..index].iter().all(|other| (
+                other.year,
+                other.day,
+                other.part,
+                &other.input
+            ) != (k.year, k.day, k.part, &k.input)));
+        }
+
+        let crt = known.iter().find(|k| (k.day, k.part) == (10, 2)).unwrap();
+        assert_eq!(check(crt), Verdict::Pass);
+
+        let e = parse("# year day part input answer\n2022\t1\t1\tin1.txt\t1\n2022 1 2 in1.txt 2\n")
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+
+    /// Answers that take minutes without optimizations, checked by `cargo test -- --ignored`.
+    const SLOW: &[(u32, u32, u32, &str)] = &[(2022, 15, 2, "in15.txt")];
+
+    /// Checks every known answer that is, or with `slow` is not, quick to, each on its own thread.
+    fn check_known(slow: bool) {
+        let known = parse(&std::fs::read_to_string(ANSWERS_FILE).unwrap()).unwrap();
+        let failures = std::thread::scope(|scope| {
+            let checks = known
+                .iter()
+                .filter(|k| SLOW.contains(&(k.year, k.day, k.part, &k.input[..])) == slow)
+                .map(|k| (k, scope.spawn(|| check(k))))
+                .collect::<Vec<_>>();
+            checks
+                .into_iter()
+                .filter_map(|(k, check)| {
+                    let verdict = check.join().unwrap_or_else(|payload| {
+                        Verdict::Fail(format!("panicked: {}", panic_message(payload)))
+                    });
+                    match verdict {
+                        Verdict::Pass => None,
+                        verdict => Some(format!(
+                            "{} day {} part {} on {}: {:?}",
+                            k.year, k.day, k.part, k.input, verdict
+                        )),
+                    }
+                })
+                .collect::<Vec<_>>()
+        });
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn known_answers() {
+        check_known(false);
+    }
+
+    #[test]
+    #[ignore]
+    fn slow_known_answers() {
+        check_known(true);
+    }
+}
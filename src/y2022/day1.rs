@@ -0,0 +1,36 @@
+use std::io::BufReader;
+
+use crate::{error::parse_field, inputs::Text, Answer, Config, Day, Error, Problem};
+
+impl Problem for Day<1> {
+    /// Calories carried by each elf, most first.
+    type Input = Vec<i32>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut sums = vec![];
+        for block in Text::read(reader)?.blocks() {
+            let mut sum = 0;
+            for (offset, &line) in block.lines.iter().enumerate() {
+                sum += parse_field::<i32>(block.line + offset, line, line, "a calorie count")?;
+            }
+            sums.push(sum);
+        }
+
+        sums.sort();
+        sums.reverse();
+        Ok(sums)
+    }
+
+    fn part1(sums: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let top1 = sums[0];
+        Ok(top1.into())
+    }
+
+    fn part2(sums: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let top3sum: i32 = sums.iter().take(3).sum();
+        Ok(top3sum.into())
+    }
+}
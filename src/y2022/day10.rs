@@ -0,0 +1,145 @@
+use std::{io::prelude::*, io::BufReader};
+
+use crate::{
+    error::Expected,
+    image::{self, Image, Palette, Rgb},
+    simulation::Simulation,
+    Answer, Config, Day, Error, Problem,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    // TODO(lubo): Implement From
+    fn from_str(line: &str) -> Result<Instruction, Expected<'_>> {
+        let args = line.split_whitespace().collect::<Vec<&str>>();
+        match args[..] {
+            ["noop"] => Ok(Instruction::Noop),
+            ["addx", val] => val
+                .parse()
+                .map(Instruction::Addx)
+                .map_err(|_| Expected::new(val, "a number")),
+            ["addx"] => Err(Expected::new(&line[line.len()..], "a number")),
+            _ => Err(Expected::new(line, "'noop' or 'addx'")),
+        }
+    }
+
+    fn parse_program<T>(reader: BufReader<T>) -> Result<Vec<Instruction>, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut program = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            program.push(Instruction::from_str(&line).map_err(|e| e.on_line(index + 1, &line))?);
+        }
+        Ok(program)
+    }
+
+    fn latency(&self) -> i32 {
+        match *self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+/// The CPU one clock cycle at a time.
+#[derive(Debug, Clone)]
+struct Cpu<'a> {
+    program: &'a [Instruction],
+    /// The instruction being executed and the cycles it still takes.
+    current: Option<(Instruction, i32)>,
+    next: usize,
+    /// The cycle under way, from 1.
+    cycle: i32,
+    /// The register during this cycle.
+    x: i32,
+}
+
+impl<'a> Cpu<'a> {
+    fn new(program: &'a [Instruction]) -> Self {
+        let mut cpu = Self {
+            program,
+            current: None,
+            next: 0,
+            cycle: 1,
+            x: 1,
+        };
+        cpu.fetch();
+        cpu
+    }
+
+    fn fetch(&mut self) {
+        if let Some(&instruction) = self.program.get(self.next) {
+            self.current = Some((instruction, instruction.latency()));
+            self.next += 1;
+        }
+    }
+}
+
+impl Simulation for Cpu<'_> {
+    type Fingerprint = ();
+
+    fn step(&mut self) {
+        match self.current {
+            Some((Instruction::Addx(val), 1)) => self.x += val,
+            Some((instruction, left)) if left > 1 => {
+                self.current = Some((instruction, left - 1));
+                self.cycle += 1;
+                return;
+            }
+            _ => (),
+        }
+        self.current = None;
+        self.cycle += 1;
+        self.fetch();
+    }
+}
+
+impl Problem for Day<10> {
+    type Input = Vec<Instruction>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        Instruction::parse_program(reader)
+    }
+
+    fn part1(program: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let mut result = 0;
+        Cpu::new(program).run_n_with(220, |cpu| {
+            if cpu.cycle % 40 == 20 {
+                result += cpu.cycle * cpu.x;
+            }
+        });
+        Ok(result.into())
+    }
+
+    fn part2(program: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut rows = vec![];
+        let mut row = String::new();
+        Cpu::new(program).run_n_with(240, |cpu| {
+            let pixel_x_position = (cpu.cycle - 1) % 40;
+            let pixel_char = match pixel_x_position - cpu.x {
+                -1..=1 => '#',
+                _ => '.',
+            };
+
+            row.push(pixel_char);
+            if pixel_x_position == 39 {
+                rows.push(std::mem::take(&mut row));
+            }
+        });
+
+        let palette = Palette::new(Rgb(20, 30, 20)).with('#', Rgb(120, 255, 120));
+        image::export(config, || Image::from_text(&rows.join("\n"), &palette))?;
+
+        Ok(Answer::Grid(rows))
+    }
+}
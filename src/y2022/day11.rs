@@ -0,0 +1,221 @@
+use std::{collections::HashMap, io::prelude::*, io::BufReader, rc::Rc};
+
+use crate::{
+    debug,
+    error::ParseError,
+    pattern::{Captures, Pattern},
+    Answer, Config, Day, Error, Problem,
+};
+use lk_math::{expr::Expr, math::*};
+
+type MonkeyId = usize;
+type WorryLevel = i64;
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<WorryLevel>,
+    #[allow(clippy::type_complexity)]
+    operation: Rc<dyn Fn(&HashMap<String, Expr<WorryLevel>>) -> WorryLevel>,
+    test: Rc<dyn Fn(WorryLevel) -> bool>,
+    target_if_true: MonkeyId,
+    target_if_false: MonkeyId,
+    items_inspected: usize,
+    division_test_value: WorryLevel,
+}
+
+const MONKEY: Pattern = Pattern::new("Monkey {}:");
+const ITEMS: Pattern = Pattern::new("Starting items: {}");
+const OPERATION: Pattern = Pattern::new("Operation: new = {}");
+const TEST: Pattern = Pattern::new("Test: divisible by {}");
+const IF_TRUE: Pattern = Pattern::new("If true: throw to monkey {}");
+const IF_FALSE: Pattern = Pattern::new("If false: throw to monkey {}");
+
+/// Matches the next line against `pattern`.
+fn next_line<'a, I>(lines: &mut I, pattern: &Pattern) -> Result<Captures<'a>, ParseError>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    let (line_number, line) = lines.next().ok_or_else(|| pattern.missing())?;
+    pattern.capture(line_number, line)
+}
+
+impl Monkey {
+    fn from_buffer<T>(reader: BufReader<T>) -> Result<HashMap<MonkeyId, Self>, Error>
+    where
+        T: std::io::Read,
+    {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+        let mut lines = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.as_str()))
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let mut results = HashMap::new();
+
+        while let Some((n, line)) = lines.next() {
+            let id = MONKEY.capture(n, line)?.parse(0, "a monkey number")?;
+            let items = next_line(&mut lines, &ITEMS)?.list(0, ',', "a worry level")?;
+
+            let operation = next_line(&mut lines, &OPERATION)?;
+            let expr: Expr<i64> = operation.parse(0, "an expression")?;
+            // Rounds only give the expression `old`, any other name would fail to evaluate.
+            let mut names = operation
+                .get(0)
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .filter(|token| token.starts_with(char::is_alphabetic));
+            if let Some(name) = names.find(|&name| name != "old") {
+                return Err(operation.error_at(name, "'old' or a number").into());
+            }
+            let operation = Rc::new(move |vals: &HashMap<_, _>| {
+                expr.eval(vals)
+                    .expect("'old' is the only name, checked when parsed")
+            });
+
+            let test = next_line(&mut lines, &TEST)?;
+            let divisible_by: WorryLevel = test.parse(0, "a divisor")?;
+            if divisible_by == 0 {
+                return Err(test.error(0, "a divisor other than 0").into());
+            }
+            let test = Rc::new(move |x| x % divisible_by == 0);
+
+            let target_if_true = next_line(&mut lines, &IF_TRUE)?.parse(0, "a monkey number")?;
+            let target_if_false = next_line(&mut lines, &IF_FALSE)?.parse(0, "a monkey number")?;
+
+            results.insert(
+                id,
+                Self {
+                    items,
+                    operation,
+                    test,
+                    target_if_true,
+                    target_if_false,
+                    items_inspected: 0,
+                    division_test_value: divisible_by,
+                },
+            );
+        }
+
+        for monkey in results.values() {
+            for target in [monkey.target_if_true, monkey.target_if_false] {
+                if !results.contains_key(&target) {
+                    return Err(ParseError::missing(format!("a monkey {}", target)).into());
+                }
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Plays the rounds, dividing worry levels by `divisor` after each inspection, and multiplies
+/// the number of items inspected by the two busiest monkeys.
+fn monkey_business(
+    monkeys: &HashMap<MonkeyId, Monkey>,
+    divisor: WorryLevel,
+    rounds: usize,
+) -> usize {
+    let mut monkeys = monkeys.clone();
+
+    let mut modulo = 1;
+    for x in monkeys.values() {
+        modulo = WorryLevel::lcm(modulo, x.division_test_value);
+    }
+    debug!("Divisor LCM: {}", modulo);
+    let straight_product: WorryLevel = monkeys.values().map(|x| x.division_test_value).product();
+    debug!("Straight product: {}", straight_product);
+
+    let mut monkey_keys: Vec<_> = monkeys.keys().copied().collect();
+    monkey_keys.sort();
+    let monkey_keys = monkey_keys;
+    for _round in 0..rounds {
+        for monkey_id in monkey_keys.iter() {
+            let monkey = monkeys.get_mut(monkey_id).unwrap();
+            let id_if_true = monkey.target_if_true;
+            let id_if_false = monkey.target_if_false;
+
+            monkey.items_inspected += monkey.items.len();
+            let (true_items, false_items): (Vec<_>, Vec<_>) = monkey
+                .items
+                .drain(..)
+                .map(|x| {
+                    let vals = HashMap::from([("old".into(), Expr::Const(x))]);
+                    ((monkey.operation)(&vals) / divisor) % modulo
+                })
+                .partition(|x| (monkey.test)(*x));
+
+            let true_target = monkeys.get_mut(&id_if_true).unwrap();
+            true_target.items.extend(true_items);
+
+            let false_target = monkeys.get_mut(&id_if_false).unwrap();
+            false_target.items.extend(false_items);
+        }
+    }
+
+    let mut inspected: Vec<_> = monkeys.values().map(|x| x.items_inspected).collect();
+    inspected.sort();
+    inspected.reverse();
+    inspected.iter().take(2).product()
+}
+
+/// The `divisor` parameter, which worry levels are divided by after each inspection.
+fn divisor(config: &Config, default: WorryLevel) -> Result<WorryLevel, Error> {
+    match config.get("divisor", default)? {
+        0 => Err(Error::Parameter {
+            key: "divisor".into(),
+            value: "0".into(),
+        }),
+        divisor => Ok(divisor),
+    }
+}
+
+impl Problem for Day<11> {
+    type Input = HashMap<MonkeyId, Monkey>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        Monkey::from_buffer(reader)
+    }
+
+    fn part1(monkeys: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let divisor = divisor(config, 3)?;
+        let rounds = config.get("rounds", 20)?;
+        Answer::try_from(monkey_business(monkeys, divisor, rounds))
+    }
+
+    fn part2(monkeys: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let divisor = divisor(config, 1)?;
+        let rounds = config.get("rounds", 10000)?;
+        Answer::try_from(monkey_business(monkeys, divisor, rounds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::parse_error;
+
+    #[test]
+    fn operations_only_use_old() {
+        let e = parse_error::<Day<11>>("Monkey 0:\nStarting items: 79\nOperation: new = old * x\n");
+        assert_eq!((e.line, e.column), (3, 24));
+    }
+
+    #[test]
+    fn zero_divisors_are_rejected() {
+        let input =
+            "Monkey 0:\nStarting items: 79\nOperation: new = old * 19\nTest: divisible by 0\n";
+        let e = parse_error::<Day<11>>(input);
+        assert_eq!((e.line, e.column), (4, 20));
+
+        let input = input.replace("by 0", "by 23")
+            + "If true: throw to monkey 0\nIf false: throw to monkey 0\n";
+        let monkeys = Day::<11>::parse(BufReader::new(input.as_bytes())).unwrap();
+        assert!(matches!(
+            Day::<11>::part1(&monkeys, &Config::new().with("divisor", "0")),
+            Err(Error::Parameter { key, .. }) if key == "divisor"
+        ));
+    }
+}
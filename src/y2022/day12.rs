@@ -0,0 +1,273 @@
+use std::{collections::HashMap, io::BufReader};
+
+use crate::{
+    dot::{self, Graph},
+    error::ParseError,
+    generate::Rng,
+    image::{self, Image, Rgb},
+    info,
+    search::CountedExploration,
+    Answer, Config, Day, Error, Problem,
+};
+use lk_math::prelude::*;
+use lk_math::{
+    explore::{Exploration, ExploreSignals},
+    sketch::QueueBag,
+};
+
+/// Reads the heightmap and replaces the start and end markers with their heights.
+fn parse_heightmap<T>(reader: BufReader<T>) -> Result<(Array2d<char>, V2i32, V2i32), Error>
+where
+    T: std::io::Read,
+{
+    let mut map =
+        Array2d::from_buffer(reader).map_err(|_| ParseError::missing("a rectangular heightmap"))?;
+    let start = map
+        .find_item(&'S')
+        .ok_or_else(|| ParseError::missing("a start square 'S'"))?;
+    let end = map
+        .find_item(&'E')
+        .ok_or_else(|| ParseError::missing("a goal square 'E'"))?;
+    map.set(start, 'a');
+    map.set(end, 'z');
+    Ok((map, start, end))
+}
+
+/// The squares from `from` back to where the search started, following `came_from`.
+fn route(came_from: &HashMap<V2i32, V2i32>, from: V2i32) -> Vec<V2i32> {
+    let mut route = vec![from];
+    while let Some(&previous) = came_from.get(route.last().unwrap()) {
+        route.push(previous);
+    }
+    route
+}
+
+/// Low ground dark green rising to light brown, with the route in red.
+fn draw_route(map: &Array2d<char>, route: &[V2i32]) -> Image {
+    let mut image = Image::new(map.width(), map.height(), Rgb::BLACK);
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let p = V2::from_xy(x as i32, y as i32);
+            let height = (*map.get(p).unwrap() as u8).saturating_sub(b'a') as f32 / 25.0;
+            image.set(x, y, Rgb(20, 70, 30).mix(Rgb(230, 210, 170), height));
+        }
+    }
+    for p in route {
+        image.set(p.x() as usize, p.y() as usize, Rgb(230, 40, 40));
+    }
+    image
+}
+
+/// Every square with an edge to each neighbour it can climb to, pinned to its place in the grid
+/// for `neato`.
+fn draw_graph(map: &Array2d<char>, start: V2i32, end: V2i32) -> Graph {
+    let id = |p: V2i32| format!("{},{}", p.x(), p.y());
+    let mut graph = Graph::directed();
+    for y in 0..map.height() as i32 {
+        for x in 0..map.width() as i32 {
+            let p = V2::from_xy(x, y);
+            let height = *map.get(p).unwrap();
+            let label = if p == start {
+                format!("S ({})", height)
+            } else if p == end {
+                format!("E ({})", height)
+            } else {
+                height.to_string()
+            };
+            graph.node_with(id(p), &label, &format!("pos=\"{},{}!\"", x, -y));
+            for n in [(1, 0), (0, 1), (-1, 0), (0, -1)].map(|(dx, dy)| V2::from_xy(x + dx, y + dy))
+            {
+                if map.get(n).is_some_and(|&to| to as i32 <= height as i32 + 1) {
+                    graph.edge(id(p), id(n));
+                }
+            }
+        }
+    }
+    graph
+}
+
+/// The error for a heightmap where no path climbs from `from` to the goal.
+fn no_path(from: &str) -> Error {
+    Error::NoSolution(format!("no path climbs from {} to 'E'", from))
+}
+
+/// Searches down from the goal, the reverse of climbing, until `is_goal` holds for a square and
+/// its height. `goal_name` names those squares for when none can be reached.
+fn descend(
+    map: &Array2d<char>,
+    end: V2i32,
+    config: &Config,
+    goal_name: &str,
+    is_goal: impl Fn(V2i32, char) -> bool,
+) -> Result<Answer, Error> {
+    let mut distances = map.map(|_| 999999999);
+    distances.set(end, 0);
+    let mut came_from = HashMap::new();
+
+    let mut goal = end;
+    let mut result = None;
+    let mut exp = Exploration::new(map.clone(), distances);
+    let stats = exp.explore_avoid_identical_counted::<_, _, QueueBag<_>>(
+        end,
+        |p, map, distances| {
+            if is_goal(*p, *map.get(*p).unwrap()) {
+                goal = *p;
+                result = Some(*distances.get(*p).unwrap());
+                ExploreSignals::ReachedGoal
+            } else {
+                ExploreSignals::Explore
+            }
+        },
+        |p, n, map, distances| {
+            let from = *map.get(*p).unwrap() as i32;
+            let to = *map.get(*n).unwrap() as i32;
+            let accessible = from - 1 <= to;
+            if accessible {
+                let dist_n = *distances.get(*n).unwrap();
+                let dist_p = *distances.get(*p).unwrap();
+                let relaxed = dist_p + 1;
+                if relaxed < dist_n {
+                    distances.set(*n, relaxed);
+                    came_from.insert(*n, *p);
+                }
+            }
+            accessible
+        },
+    );
+    info!("{}", stats);
+    let result = result.ok_or_else(|| no_path(goal_name))?;
+
+    image::export(config, || draw_route(map, &route(&came_from, goal)))?;
+    dot::export(config, || draw_graph(map, goal, end))?;
+
+    Ok(result.into())
+}
+
+impl Problem for Day<12> {
+    /// The heightmap with the start and end marked as 'a' and 'z', and their positions.
+    type Input = (Array2d<char>, V2i32, V2i32);
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        parse_heightmap(reader)
+    }
+
+    fn part1((map, start, end): &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let (start, end) = (*start, *end);
+        let mut distances = map.map(|_| 999999999);
+        distances.set(start, 0);
+        let mut came_from = HashMap::new();
+
+        let mut result = None;
+        let mut exp = Exploration::new(map.clone(), distances);
+        let stats = exp.explore_avoid_identical_counted::<_, _, QueueBag<_>>(
+            start,
+            |p, _map, distances| {
+                if p == &end {
+                    result = Some(*distances.get(*p).unwrap());
+                    ExploreSignals::ReachedGoal
+                } else {
+                    ExploreSignals::Explore
+                }
+            },
+            |p, n, map, distances| {
+                let from = *map.get(*p).unwrap() as i32;
+                let to = *map.get(*n).unwrap() as i32;
+                let accessible = from + 1 >= to;
+                if accessible {
+                    let dist_n = *distances.get(*n).unwrap();
+                    let dist_p = *distances.get(*p).unwrap();
+                    let relaxed = dist_p + 1;
+                    if relaxed < dist_n {
+                        distances.set(*n, relaxed);
+                        came_from.insert(*n, *p);
+                    }
+                }
+                accessible
+            },
+        );
+        info!("{}", stats);
+        let result = result.ok_or_else(|| no_path("'S'"))?;
+
+        image::export(config, || draw_route(map, &route(&came_from, end)))?;
+        dot::export(config, || draw_graph(map, start, end))?;
+
+        Ok(result.into())
+    }
+
+    fn part2((map, _start, end): &Self::Input, config: &Config) -> Result<Answer, Error> {
+        descend(
+            map,
+            *end,
+            config,
+            "any square at height 'a'",
+            |_, height| height == 'a',
+        )
+    }
+}
+
+/// Part 1 searched the other way, down from the goal to the start, to check the uphill search
+/// against.
+pub struct Day12Downhill;
+
+impl Problem for Day12Downhill {
+    type Input = <Day<12> as Problem>::Input;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        parse_heightmap(reader)
+    }
+
+    fn part1((map, start, end): &Self::Input, config: &Config) -> Result<Answer, Error> {
+        descend(map, *end, config, "'S'", |p, _| p == *start)
+    }
+}
+
+/// A heightmap `size` squares wide and a fifth as high rising from left to right, with some
+/// bumps that can block the way. The start is on the left edge, the goal on the right.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(2);
+    let height = (width / 5).max(1);
+    let start = rng.below(height as u64) as usize;
+    let end = rng.below(height as u64) as usize;
+
+    let mut text = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let rise = (x * 26 / width) as i64;
+            let square = match (x, y) {
+                (0, y) if y == start => 'S',
+                (x, y) if x + 1 == width && y == end => 'E',
+                _ => char::from(b'a' + (rise + rng.range(-2..=1)).clamp(0, 25) as u8),
+            };
+            text.push(square);
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreachable_goal_is_no_solution() {
+        let input = Day::<12>::parse(BufReader::new("SbE\n".as_bytes())).unwrap();
+        let config = Config::new();
+        let uphill = Day::<12>::part1(&input, &config).unwrap_err().to_string();
+        assert_eq!(uphill, "no solution: no path climbs from 'S' to 'E'");
+        let downhill = Day12Downhill::part1(&input, &config)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(downhill, uphill);
+        assert!(matches!(
+            Day::<12>::part2(&input, &config),
+            Err(Error::NoSolution(_))
+        ));
+    }
+}
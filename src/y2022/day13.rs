@@ -1,9 +1,13 @@
 use std::{cmp::Ordering, io::prelude::*, io::BufReader};
 
-use crate::{Day, Problem};
+use crate::{
+    error::{Expected, ParseError},
+    trace, Answer, Config, Day, Error, Problem,
+};
 
+/// A packet, ordered by the puzzle's comparison rules.
 #[derive(Debug, Clone, Eq)]
-enum Token {
+pub enum Token {
     Value(i32),
     List(Vec<Token>),
 }
@@ -21,8 +25,10 @@ impl<T> InsertIfSome<T> for Vec<T> {
 }
 
 impl Token {
-    fn parse_value(s: &str) -> Self {
-        Self::Value(s.parse().unwrap())
+    fn parse_value(s: &str) -> Result<Self, Expected<'_>> {
+        s.parse()
+            .map(Self::Value)
+            .map_err(|_| Expected::new(s, "a number"))
     }
 
     fn make_list(&self) -> Self {
@@ -32,64 +38,55 @@ impl Token {
         }
     }
 
-    fn parse_list(s: &str) -> Self {
-        assert!(s.starts_with('['));
-        assert!(s.ends_with(']'));
+    fn parse_list(s: &str) -> Result<Self, Expected<'_>> {
         let mut depth = 0;
-        let mut last_delim = None;
+        let mut last_delim = 0;
         let mut elements = vec![];
-        for (idx, ch) in s.chars().enumerate() {
+        for (idx, ch) in s.char_indices() {
             match ch {
                 '[' => depth += 1,
                 ']' => depth -= 1,
-                ',' => {
-                    assert!(depth >= 1);
-                    if depth == 1 {
-                        match last_delim {
-                            Some(last) => {
-                                elements.insert_if_some(Token::from_str(&s[last + 1..idx]));
-                            }
-                            None => {
-                                elements.insert_if_some(Token::from_str(&s[1..idx]));
-                            }
-                        }
-
-                        last_delim = Some(idx);
-                    }
+                ',' if depth == 1 => {
+                    elements.insert_if_some(Token::parse(&s[last_delim + 1..idx])?);
+                    last_delim = idx;
                 }
                 _ => (),
             }
 
-            assert!(depth >= 0);
-
             if depth == 0 {
-                assert_eq!(idx, s.len() - 1);
-
-                match last_delim {
-                    Some(last) => {
-                        elements.insert_if_some(Token::from_str(&s[last + 1..idx]));
-                    }
-                    None => {
-                        elements.insert_if_some(Token::from_str(&s[1..idx]));
-                    }
+                if idx != s.len() - 1 {
+                    return Err(Expected::new(&s[idx + 1..], "the end of the packet"));
                 }
+
+                elements.insert_if_some(Token::parse(&s[last_delim + 1..idx])?);
+                return Ok(Self::List(elements));
             }
         }
-        assert_eq!(depth, 0);
-        Self::List(elements)
+        Err(Expected::new(&s[s.len()..], "']'"))
     }
 
-    fn from_str(s: &str) -> Option<Self> {
-        if let Some(ch) = s.chars().next() {
-            match ch {
-                '[' => Some(Self::parse_list(s)),
-                '0'..='9' => Some(Self::parse_value(s)),
-                _ => panic!(),
-            }
-        } else {
-            None
+    pub fn parse(s: &str) -> Result<Option<Self>, Expected<'_>> {
+        match s.chars().next() {
+            Some('[') => Self::parse_list(s).map(Some),
+            Some('0'..='9') => Self::parse_value(s).map(Some),
+            Some(_) => Err(Expected::new(s, "'[' or a number")),
+            None => Ok(None),
         }
     }
+
+    /// Parses every non-empty line as a packet.
+    pub fn parse_packets<T>(reader: BufReader<T>) -> Result<Vec<Self>, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut packets = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let packet = Token::parse(&line).map_err(|e| e.on_line(index + 1, &line))?;
+            packets.insert_if_some(packet);
+        }
+        Ok(packets)
+    }
 }
 
 impl PartialEq for Token {
@@ -149,54 +146,45 @@ impl Ord for Token {
     }
 }
 
-impl Problem for Day<1301> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+impl Problem for Day<13> {
+    type Input = Vec<Token>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        let mut packet_pair_index = 1;
-        let mut index_sum = 0;
-
-        let mut lines = reader.lines().map(|x| x.unwrap());
+        Token::parse_packets(reader)
+    }
 
-        while let Some(left) = lines.next() {
-            let right = lines.next().unwrap();
-            let _empty = lines.next();
+    fn part1(packets: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let mut index_sum = 0;
 
-            let l = Token::from_str(&left);
-            let r = Token::from_str(&right);
+        for (index, pair) in packets.chunks(2).enumerate() {
+            let packet_pair_index = index + 1;
+            let (l, r) = match pair {
+                [l, r] => (l, r),
+                _ => return Err(ParseError::missing("a second packet in the last pair").into()),
+            };
 
             if l < r {
-                // println!( "Wrong order at packet pair with index {}: A < B\nA: {}\nB: {}", packet_pair_index, left, right);
+                trace!(
+                    "Pair {} is in the right order:\n{:?}\n{:?}",
+                    packet_pair_index,
+                    l,
+                    r
+                );
                 index_sum += packet_pair_index;
             }
-
-            packet_pair_index += 1;
         }
 
-        writeln!(writer, "{}", index_sum).unwrap();
+        Answer::try_from(index_sum)
     }
-}
 
-impl Problem for Day<1302> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
-    where
-        T: std::io::Read,
-        W: std::io::Write,
-    {
-        let mut lines = reader
-            .lines()
-            .map(|x| x.unwrap())
-            .filter_map(|x| match !x.is_empty() {
-                true => Some(x),
-                false => None,
-            })
-            .map(|x| Token::from_str(&x).unwrap())
-            .collect::<Vec<_>>();
-
-        let div2 = Token::from_str("[[2]]").unwrap();
-        let div6 = Token::from_str("[[6]]").unwrap();
+    fn part2(packets: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let mut lines = packets.clone();
+
+        let div2 = Token::List(vec![Token::List(vec![Token::Value(2)])]);
+        let div6 = Token::List(vec![Token::List(vec![Token::Value(6)])]);
 
         let mut div2_idx = None;
         let mut div6_idx = None;
@@ -215,6 +203,18 @@ impl Problem for Day<1302> {
         }
         let div2_idx = div2_idx.unwrap();
         let div6_idx = div6_idx.unwrap();
-        writeln!(writer, "{}", div2_idx * div6_idx).unwrap();
+        Answer::try_from(div2_idx * div6_idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packets_order_like_the_puzzle() {
+        let packet = |s| Token::parse(s).unwrap().unwrap();
+        assert!(packet("[1,[2]]") < packet("[[1],3]"));
+        assert_eq!(packet("[[4]]"), packet("4"));
     }
 }
@@ -0,0 +1,169 @@
+use std::{io::prelude::*, io::BufReader, str::FromStr};
+
+use crate::{
+    debug,
+    error::ParseError,
+    frames::Recorder,
+    image::{self, Image, Palette, Rgb},
+    simulation::Simulation,
+    trace, Answer, Config, Day, Error, Problem,
+};
+use lk_math::{
+    line::Line,
+    prelude::*,
+    transformations::{Transform, Translation},
+    vector::Scalar,
+};
+
+fn cave_palette() -> Palette {
+    Palette::new(Rgb(230, 60, 60))
+        .with('.', Rgb(30, 24, 20))
+        .with('#', Rgb(130, 120, 110))
+        .with('o', Rgb(230, 190, 90))
+}
+
+/// The cave one grain of sand at a time.
+#[derive(Clone)]
+struct Cave {
+    map: Array2d<char>,
+    source: V2<i32>,
+    came_to_rest: usize,
+    /// Sand flows into the abyss or covers the source.
+    full: bool,
+}
+
+impl Simulation for Cave {
+    type Fingerprint = ();
+
+    /// Drops a grain from the source until it comes to rest or falls out of the cave.
+    fn step(&mut self) {
+        let mut sand = self.source;
+        loop {
+            let a = sand.step_up().unwrap();
+            match self.map.get(a) {
+                Some(atile) => {
+                    let b = a.step_left().unwrap();
+                    let c = a.step_right().unwrap();
+                    if atile == &'.' {
+                        sand = a;
+                    } else if self.map.get(b).unwrap() == &'.' {
+                        sand = b;
+                    } else if self.map.get(c).unwrap() == &'.' {
+                        sand = c;
+                    } else {
+                        self.came_to_rest += 1;
+                        if sand == self.source {
+                            self.full = true;
+                        } else {
+                            self.map.set(sand, 'o');
+                        }
+                        return;
+                    }
+                }
+                None => {
+                    self.full = true;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Pours sand until it flows into the abyss or, with a floor (`C`), until the source is covered.
+/// The cave is recorded after every grain that comes to rest.
+fn pour_sand<const C: bool>(paths: &[Vec<V2<i32>>], config: &Config) -> Result<usize, Error> {
+    let mut recorder = Recorder::from_config(config)?.with_palette(cave_palette());
+    let sand_coords = V2::from_xy(500, 0);
+    let mut aabb = Aabb2::new(sand_coords, sand_coords);
+    for coords in paths.iter().flatten() {
+        aabb.cover(coords);
+    }
+
+    let floor_y = aabb.max.y() + 2;
+    let sand_height = floor_y;
+    let floor_x_min = sand_coords.x() - sand_height - 1;
+    let floor_x_max = sand_coords.x() + sand_height + 1;
+    let floor_a = V2::from_xy(floor_x_min, floor_y);
+    let floor_b = V2::from_xy(floor_x_max, floor_y);
+    aabb.cover(&floor_a);
+    aabb.cover(&floor_b);
+
+    let map_dim = aabb.dim() + V2::from_xy(1, 1) + Scalar::new(4) * V2::from_xy(1, 1);
+    let t = Translation::new(aabb.min - V2::from_xy(2, 2));
+
+    let mut map = Array2d::with_dimensions(map_dim.x() as usize, map_dim.y() as usize, '.');
+    let sand_coords = t.inverse_transform(sand_coords);
+    map.set(sand_coords, '+');
+    let floor_a = t.inverse_transform(floor_a);
+    let floor_b = t.inverse_transform(floor_b);
+    if C {
+        map.draw_line::<true>(Line::new(floor_a, floor_b), '#');
+    }
+
+    for path in paths.iter() {
+        let mut prev: Option<V2<_>> = None;
+        for &coords in path.iter() {
+            let coords = t.inverse_transform(coords);
+            if let Some(prev) = prev {
+                map.draw_line::<true>(Line::new(prev, coords), '#');
+            }
+            prev = Some(coords);
+        }
+    }
+    trace!("{}", map);
+
+    let mut cave = Cave {
+        map,
+        source: sand_coords,
+        came_to_rest: 0,
+        full: false,
+    };
+    cave.run_until(|cave| {
+        if cave.came_to_rest > 0 && !cave.full {
+            recorder.step(|| cave.map.to_string());
+        }
+        cave.full
+    });
+    let (map, came_to_rest) = (cave.map, cave.came_to_rest);
+    recorder.capture(|| map.to_string());
+    recorder.finish()?;
+    image::export(config, || {
+        Image::from_text(&map.to_string(), &cave_palette())
+    })?;
+    debug!("{} grains of sand came to rest.", came_to_rest);
+
+    Ok(came_to_rest)
+}
+
+impl Problem for Day<14> {
+    /// Rock paths as lists of corner coordinates.
+    type Input = Vec<Vec<V2<i32>>>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut paths = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let mut path = vec![];
+            for coords in line.split("->") {
+                let coords = coords.trim();
+                let coords = V2::<i32>::from_str(coords).map_err(|_| {
+                    ParseError::at(index + 1, &line, coords, "coordinates like '498,4'")
+                })?;
+                path.push(coords);
+            }
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    fn part1(paths: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(pour_sand::<false>(paths, config)?)
+    }
+
+    fn part2(paths: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(pour_sand::<true>(paths, config)?)
+    }
+}
@@ -0,0 +1,203 @@
+use std::{collections::HashSet, io::prelude::*, io::BufReader};
+
+use crate::{debug, generate::Rng, pattern::Pattern, trace, Answer, Config, Day, Error, Problem};
+use lk_math::{interval::InclusiveMin, interval_set::IntervalSet, prelude::*};
+
+/// Sensor position and the closest beacon it picks up.
+type Reading = (V2<i32>, V2<i32>);
+
+fn parse_sensors_data<T>(reader: BufReader<T>) -> Result<Vec<Reading>, Error>
+where
+    T: std::io::Read,
+{
+    const READING: Pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+
+    let mut result = vec![];
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let fields = READING.capture(index + 1, &line)?;
+        let coordinate = |i| fields.parse(i, "a coordinate");
+        let s = V2::from_xy(coordinate(0)?, coordinate(1)?);
+        let b = V2::from_xy(coordinate(2)?, coordinate(3)?);
+        result.push((s, b));
+    }
+    Ok(result)
+}
+
+fn unzip_vec_of_2tuple<T: Clone>(v: &[(T, T)]) -> (Vec<T>, Vec<T>) {
+    // v.iter().cloned().unzip::<_, _, Vec<_>, Vec<_>>()
+    v.iter().cloned().unzip()
+}
+
+impl Problem for Day<15> {
+    type Input = Vec<Reading>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        parse_sensors_data(reader)
+    }
+
+    fn part1(a: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        // The example looks at row 10 instead.
+        let y: i32 = config.get("row", 2000000)?;
+        let (sensors, beacons) = unzip_vec_of_2tuple(a);
+        let mut objects = sensors;
+        objects.extend(beacons.iter());
+
+        let objects_on_line = objects
+            .iter()
+            .filter(|p| p.y() == y)
+            .map(|p| p.x())
+            .collect::<HashSet<_>>();
+
+        let mut interval_set = IntervalSet::new();
+
+        for (s, b) in a.iter() {
+            let range = s.manhattan_distance(b);
+            let distance_to_line = (s.y() - y).abs();
+            let range_remaining = range - distance_to_line;
+            if range_remaining > 0 {
+                let range_x_min = s.x() - range_remaining;
+                let range_x_max = s.x() + range_remaining;
+                let covering_line_x = range_x_min..range_x_max + 1;
+                interval_set.union(covering_line_x);
+            }
+        }
+
+        let cant_be = interval_set.measure() as usize - objects_on_line.len();
+
+        Answer::try_from(cant_be)
+    }
+
+    fn part2(a: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        // Side of the square searched, starting at 0. The example uses 21.
+        let c: i32 = config.get("size", 4000000)?;
+        let (sensors, beacons) = unzip_vec_of_2tuple(a);
+        let mut objects = sensors;
+        objects.extend(beacons.iter());
+
+        /*
+        NOTE(lubo): This approach works, but is not good.
+        We should do simulated annealing/hill climbing instead (particles
+        pushed away from sensors if they are within range until they find
+        a spot where they remain in rest)
+        */
+        let mut found = None;
+        let mut ticker = config.cancel().ticker();
+        for line_y in 0..c {
+            if ticker.tick() {
+                return Err(Error::TimedOut);
+            }
+            let mut interval_set = IntervalSet::new();
+
+            for (s, b) in a.iter() {
+                let range = s.manhattan_distance(b);
+                let distance_to_line = (s.y() - line_y).abs();
+                let range_remaining = range - distance_to_line;
+                if range_remaining > 0 {
+                    let range_x_min = s.x() - range_remaining;
+                    let range_x_max = s.x() + range_remaining;
+                    let covering_line_x = range_x_min..range_x_max + 1;
+                    interval_set.union(covering_line_x);
+                }
+            }
+
+            interval_set.intersect(0..c);
+
+            if line_y % 100000 == 0 {
+                trace!("Line Y = {}", line_y);
+            }
+
+            let measure = interval_set.measure();
+            if measure != c {
+                debug!(
+                    "Line Y {} Measure {} Interval Set {:?} Negated in bounds {:?}",
+                    line_y,
+                    measure,
+                    interval_set,
+                    interval_set.negation_within_bounds()
+                );
+
+                let negated = interval_set.negation_within_bounds();
+                // The scan goes on after the gap, so a second one anywhere is noticed.
+                if found.is_some() || negated.measure() != 1 {
+                    return Err(Error::NoSolution(
+                        "more than one tile no sensor covers".into(),
+                    ));
+                }
+                let x = *negated.intervals[0].inclusive_min();
+                let tuning_frequency = 4000000i64 * x as i64 + line_y as i64;
+
+                debug!(
+                    "x: {} y: {} tuning_frequency: {}",
+                    x, line_y, tuning_frequency
+                );
+
+                found = Some(tuning_frequency);
+            }
+        }
+
+        found
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("every tile is covered by a sensor".into()))
+    }
+}
+
+/// `size` sensors around the square part 2 searches, each reporting the one beacon closest to it.
+/// Random sensors hardly ever leave the single gap part 2 looks for, so these are mostly good
+/// for part 1 and the parser.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const EXTENT: i64 = 4000000;
+    let distance = |a: (i64, i64), b: (i64, i64)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+    let point = |rng: &mut Rng| (rng.range(0..=EXTENT), rng.range(0..=EXTENT));
+
+    // Fewer beacons than sensors, so some are shared like in the real input.
+    let beacons = (0..size / 3 + 1).map(|_| point(rng)).collect::<Vec<_>>();
+    let mut sensors = vec![];
+    let mut text = String::new();
+    while sensors.len() < size.max(1) {
+        let s = point(rng);
+        let mut by_distance = beacons
+            .iter()
+            .map(|&b| (distance(s, b), b))
+            .collect::<Vec<_>>();
+        by_distance.sort();
+        // A sensor is never equally close to two beacons.
+        let tied = by_distance.get(1).is_some_and(|d| d.0 == by_distance[0].0);
+        if tied || sensors.contains(&s) || beacons.contains(&s) {
+            continue;
+        }
+        let b = by_distance[0].1;
+        sensors.push(s);
+        text.push_str(&format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            s.0, s.1, b.0, b.1
+        ));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn needs_exactly_one_gap() {
+        let config = Config::new().with("size", "21");
+        let part2 = |x, beacon_y| {
+            let input = format!(
+                "Sensor at x={}, y=10: closest beacon is at x={}, y={}\n",
+                x, x, beacon_y
+            );
+            let sensors = Day::<15>::parse(BufReader::new(input.as_bytes())).unwrap();
+            Day::<15>::part2(&sensors, &config)
+        };
+        // Out of reach of 19, (0, 0) and (20, 0) are two gaps on the same line.
+        assert!(matches!(part2(10, 29), Err(Error::NoSolution(_))));
+        assert!(matches!(part2(10, 40), Err(Error::NoSolution(_))));
+        // Out of reach of 29, (20, 0) and (20, 20) are single gaps on two lines.
+        assert!(matches!(part2(0, 39), Err(Error::NoSolution(_))));
+    }
+}
@@ -2,9 +2,18 @@ use std::{collections::HashMap, io::prelude::*, io::BufReader, vec};
 
 use lk_math::prelude::*;
 
+use crate::{
+    dot::{self, Graph},
+    error::ParseError,
+    generate::Rng,
+    pattern::Pattern,
+    y2022::{day16_part1::release_pressure_alone, day16_part2::release_pressure_with_elephant},
+    Answer, Config, Day, Error, Problem,
+};
+
 pub type RoomId = i32;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Rooms {
     pub collection: HashMap<RoomId, Room>,
     pub room_ids: HashMap<String, RoomId>,
@@ -57,32 +66,64 @@ impl Rooms {
         }
     }
 
-    pub fn from_buffer<T>(reader: BufReader<T>) -> Self
+    /// The valves with their flow rates and the tunnels between them. The valves worth opening
+    /// and the start are also linked by dashed edges, labeled with the shortest distance found.
+    pub fn draw(&self) -> Graph {
+        let name = |id: &RoomId| &self.room_names[*id as usize];
+        let mut graph = Graph::undirected();
+        let mut ids = self.collection.keys().collect::<Vec<_>>();
+        ids.sort();
+        for id in ids.iter().copied() {
+            let room = &self.collection[id];
+            let label = format!("{}\nrate {}", name(id), room.pressure);
+            match room.pressure {
+                0 => graph.node(name(id), &label),
+                _ => graph.node_with(name(id), &label, "style=bold"),
+            }
+            for to in room.connections.iter().filter(|to| *to > id) {
+                graph.edge(name(id), name(to));
+            }
+        }
+
+        // The search starts in the first room, AA.
+        let mut ends = self.interesting_rooms.clone();
+        ends.push(0);
+        ends.sort();
+        ends.dedup();
+        for (i, from) in ends.iter().enumerate() {
+            for to in &ends[i + 1..] {
+                let distance = self.distances[&(*from, *to)];
+                let attributes = format!("style=dashed, constraint=false, label=\"{}\"", distance);
+                graph.edge_with(name(from), name(to), &attributes);
+            }
+        }
+        graph
+    }
+
+    pub fn from_buffer<T>(reader: BufReader<T>) -> Result<Self, Error>
     where
         T: std::io::Read,
     {
-        let mut rooms_raw = vec![];
+        // A valve with a single tunnel is described in the singular.
+        const VALVE: [Pattern; 2] = [
+            Pattern::new("Valve {} has flow rate={}; tunnels lead to valves {}"),
+            Pattern::new("Valve {} has flow rate={}; tunnel leads to valve {}"),
+        ];
 
-        for line in reader.lines().map(|x| x.unwrap()) {
-            // writeln!("{}", line);
-
-            let a = line.split("Valve").collect::<Vec<_>>()[1];
-            let a = a.split("has flow rate=").collect::<Vec<_>>();
-            let valve = a[0].trim().to_string();
-            let a = a[1].split(';').collect::<Vec<_>>();
-            let pressure = a[0].trim().parse().unwrap();
-            let tunnels = a[1]
-                .split(',')
-                .map(|x| x.split_whitespace().last().unwrap().to_string())
-                .collect::<Vec<_>>();
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+        let mut rooms_raw = vec![];
 
-            rooms_raw.push((valve, pressure, tunnels));
+        for (index, line) in lines.iter().enumerate() {
+            let fields = Pattern::capture_any(&VALVE, index + 1, line)?;
+            let valve = fields.ident(0, "a valve name")?;
+            let pressure: i32 = fields.parse(1, "a flow rate")?;
+            let tunnels = fields.items(2, ',').collect::<Vec<_>>();
+            rooms_raw.push((index + 1, line, valve, pressure, tunnels));
         }
 
         let mut room_names = rooms_raw
             .iter()
-            .map(|(name, _, _)| name)
-            .cloned()
+            .map(|(_, _, name, _, _)| name.to_string())
             .collect::<Vec<_>>();
         room_names.sort();
 
@@ -94,20 +135,25 @@ impl Rooms {
             .collect::<HashMap<_, _>>();
 
         let mut rooms = HashMap::new();
-        rooms_raw.iter().for_each(|(name, pressure, tunnels)| {
-            let room_id = *room_ids.get(name).unwrap();
+        for (n, line, name, pressure, tunnels) in rooms_raw.iter() {
+            let room_id = *room_ids.get(*name).unwrap();
+            let connections = tunnels
+                .iter()
+                .map(|x| {
+                    room_ids.get(*x).copied().ok_or_else(|| {
+                        ParseError::at(*n, line, x, "a valve described in the input")
+                    })
+                })
+                .collect::<Result<_, _>>()?;
             rooms.insert(
                 room_id,
                 Room {
                     pressure: *pressure,
-                    connections: tunnels
-                        .iter()
-                        .map(|x| *room_ids.get(x).unwrap() as RoomId)
-                        .collect(),
+                    connections,
                     floyd_warshall_connections: vec![],
                 },
             );
-        });
+        }
 
         let interesting_rooms = rooms
             .iter()
@@ -132,11 +178,32 @@ impl Rooms {
             }
         }
 
-        rooms
+        Ok(rooms)
+    }
+}
+
+impl Problem for Day<16> {
+    type Input = Rooms;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        Rooms::from_buffer(reader)
+    }
+
+    fn part1(rooms: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        dot::export(config, || rooms.draw())?;
+        Answer::try_from(release_pressure_alone(rooms, config.cancel())?)
+    }
+
+    fn part2(rooms: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        dot::export(config, || rooms.draw())?;
+        Answer::try_from(release_pressure_with_elephant(rooms, config.cancel())?)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Room {
     // id: RoomId,
     pub pressure: i32,
@@ -219,3 +286,62 @@ where
         }
     }
 }
+
+/// `size` valves in one tunnel network reachable from `AA`, about a quarter of them with a flow
+/// rate. At most 64, the searches keep the open valves in a bitmask.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(2, 64);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < size {
+        let letter = |rng: &mut Rng| char::from(b'A' + rng.below(26) as u8);
+        let name = format!("{}{}", letter(rng), letter(rng));
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut tunnels = vec![vec![]; size];
+    let mut dig = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    // A random tree keeps every valve reachable, the extra tunnels add loops.
+    for a in 1..size {
+        dig(a, rng.below(a as u64) as usize);
+    }
+    for _ in 0..size / 2 {
+        dig(
+            rng.below(size as u64) as usize,
+            rng.below(size as u64) as usize,
+        );
+    }
+
+    let mut order = (0..size).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    let mut text = String::new();
+    for valve in order {
+        let rate = match valve > 0 && rng.one_in(4) {
+            true => rng.range(1..=25),
+            false => 0,
+        };
+        let leads = tunnels[valve]
+            .iter()
+            .map(|&b| names[b].as_str())
+            .collect::<Vec<_>>();
+        let (verb, noun) = match leads.len() {
+            1 => ("tunnel leads", "valve"),
+            _ => ("tunnels lead", "valves"),
+        };
+        text.push_str(&format!(
+            "Valve {} has flow rate={}; {} to {} {}\n",
+            names[valve],
+            rate,
+            verb,
+            noun,
+            leads.join(", ")
+        ));
+    }
+    text
+}
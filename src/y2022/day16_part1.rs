@@ -1,8 +1,11 @@
-use std::{cmp::Ordering, fmt::Display, hash::Hash, io::BufReader, vec};
+use std::{cmp::Ordering, fmt::Display, hash::Hash, vec};
 
 use crate::{
-    day16::{PointTrait, RoomId, Rooms},
-    Day, Problem,
+    cancel::Cancel,
+    info,
+    search::CountedExploration,
+    y2022::day16::{PointTrait, RoomId, Rooms},
+    Error,
 };
 use lk_math::{
     explore::{Exploration, ExploreSignals, PointKeyValue},
@@ -131,37 +134,37 @@ impl IterateNeighbours<Rooms> for Point {
     }
 }
 
-impl Problem for Day<1601> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
-    where
-        T: std::io::Read,
-        W: std::io::Write,
-    {
-        let rooms = Rooms::from_buffer(reader);
-        let mut exp = Exploration::new(rooms, ());
-        let mut max_pressure_released = 0;
-
-        exp.explore_avoid_worse::<_, _, StackBag<_>>(
-            Point::initial(0, &exp.context),
-            |p, rooms, _| {
-                let state_potential = p.state_potential_overestimate(rooms);
-
-                if state_potential == 0 {
-                    return ExploreSignals::Skip;
-                }
-                if p.pressure_released + state_potential <= max_pressure_released {
-                    return ExploreSignals::Skip;
-                }
-
-                if p.pressure_released > max_pressure_released {
-                    max_pressure_released = p.pressure_released;
-                }
-
-                ExploreSignals::Explore
-            },
-            |_p, _n, _rooms, _| true,
-        );
-
-        writeln!(writer, "{}", max_pressure_released).unwrap();
-    }
+/// Most pressure one can release alone in 30 minutes.
+pub fn release_pressure_alone(rooms: &Rooms, cancel: &Cancel) -> Result<u64, Error> {
+    let mut exp = Exploration::new(rooms.clone(), ());
+    let mut max_pressure_released = 0;
+    let mut ticker = cancel.ticker();
+
+    let stats = exp.explore_avoid_worse_counted::<_, _, StackBag<_>>(
+        Point::initial(0, &exp.context),
+        |p, rooms, _| {
+            if ticker.tick() {
+                return ExploreSignals::ReachedGoal;
+            }
+            let state_potential = p.state_potential_overestimate(rooms);
+
+            if state_potential == 0 {
+                return ExploreSignals::Skip;
+            }
+            if p.pressure_released + state_potential <= max_pressure_released {
+                return ExploreSignals::Skip;
+            }
+
+            if p.pressure_released > max_pressure_released {
+                max_pressure_released = p.pressure_released;
+            }
+
+            ExploreSignals::Explore
+        },
+        |_p, _n, _rooms, _| true,
+    );
+    info!("{}", stats);
+
+    ticker.check()?;
+    Ok(max_pressure_released)
 }
@@ -1,8 +1,12 @@
-use std::{cmp::Ordering, hash::Hash, io::BufReader, vec};
+use std::{cmp::Ordering, hash::Hash, vec};
 
 use crate::{
-    day16::{PointTrait, Rooms},
-    Day, Problem,
+    cancel::Cancel,
+    info,
+    search::CountedExploration,
+    trace,
+    y2022::day16::{PointTrait, Rooms},
+    Error,
 };
 use lk_math::{
     explore::{Exploration, ExploreSignals, PointKeyValue},
@@ -10,7 +14,7 @@ use lk_math::{
     sketch::StackBag,
 };
 
-use crate::day16::RoomId;
+use crate::y2022::day16::RoomId;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 struct Point2 {
@@ -152,38 +156,38 @@ impl IterateNeighbours<Rooms> for Point2 {
     }
 }
 
-impl Problem for Day<1602> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
-    where
-        T: std::io::Read,
-        W: std::io::Write,
-    {
-        let rooms = Rooms::from_buffer(reader);
-        let mut exp = Exploration::new(rooms, ());
-        let mut max_pressure_released = 0;
-
-        exp.explore_avoid_worse::<_, _, StackBag<_>>(
-            Point2::initial(0, &exp.context),
-            |p, rooms, _| {
-                let state_potential = p.state_potential_overestimate(rooms);
-
-                if state_potential == 0 {
-                    return ExploreSignals::Skip;
-                }
-                if p.pressure_released + state_potential <= max_pressure_released {
-                    return ExploreSignals::Skip;
-                }
-
-                if p.pressure_released > max_pressure_released {
-                    // println!("New best PRESSURE {} @ {:?}", max_pressure_released, p);
-                    max_pressure_released = p.pressure_released;
-                }
-
-                ExploreSignals::Explore
-            },
-            |_p, _n, _rooms, _| true,
-        );
-
-        writeln!(writer, "{}", max_pressure_released).unwrap();
-    }
+/// Most pressure one can release in 26 minutes with the help of an elephant.
+pub fn release_pressure_with_elephant(rooms: &Rooms, cancel: &Cancel) -> Result<u64, Error> {
+    let mut exp = Exploration::new(rooms.clone(), ());
+    let mut max_pressure_released = 0;
+    let mut ticker = cancel.ticker();
+
+    let stats = exp.explore_avoid_worse_counted::<_, _, StackBag<_>>(
+        Point2::initial(0, &exp.context),
+        |p, rooms, _| {
+            if ticker.tick() {
+                return ExploreSignals::ReachedGoal;
+            }
+            let state_potential = p.state_potential_overestimate(rooms);
+
+            if state_potential == 0 {
+                return ExploreSignals::Skip;
+            }
+            if p.pressure_released + state_potential <= max_pressure_released {
+                return ExploreSignals::Skip;
+            }
+
+            if p.pressure_released > max_pressure_released {
+                max_pressure_released = p.pressure_released;
+                trace!("New best pressure {} @ {:?}", max_pressure_released, p);
+            }
+
+            ExploreSignals::Explore
+        },
+        |_p, _n, _rooms, _| true,
+    );
+    info!("{}", stats);
+
+    ticker.check()?;
+    Ok(max_pressure_released)
 }
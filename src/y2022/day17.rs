@@ -1,5 +1,31 @@
+use std::io::{BufRead, BufReader};
+
+use crate::{error::ParseError, generate::Rng, Error};
 use lk_math::prelude::*;
 
+/// Reads the jet pattern, `true` meaning a push to the right.
+pub fn parse_jets<T>(reader: BufReader<T>) -> Result<Vec<bool>, Error>
+where
+    T: std::io::Read,
+{
+    let mut jets = vec![];
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        for (i, ch) in line.char_indices() {
+            jets.push(match ch {
+                '>' => true,
+                '<' => false,
+                _ => return Err(ParseError::at(index + 1, &line, &line[i..], "'<' or '>'").into()),
+            });
+        }
+    }
+
+    if jets.is_empty() {
+        return Err(ParseError::missing("a jet pattern").into());
+    }
+    Ok(jets)
+}
+
 pub struct Rock;
 impl Rock {
     pub fn width(t: usize) -> i32 {
@@ -75,3 +101,12 @@ impl Rock {
         Self::construct_blueprint_mask(Self::right_side())
     }
 }
+
+/// `size` random jets.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = (0..size.max(1))
+        .map(|_| *rng.pick(&['<', '>']))
+        .collect::<String>();
+    text.push('\n');
+    text
+}
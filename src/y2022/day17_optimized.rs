@@ -0,0 +1,265 @@
+use std::io::BufReader;
+
+use crate::{
+    debug,
+    simulation::Simulation,
+    y2022::day17::{parse_jets, Rock},
+    Answer, Config, Error, Problem,
+};
+
+/// Part 1 for `C` rocks on a tower of bitmasks, skipping ahead once the top of the tower repeats.
+/// Part 2 of `Day<17>` uses its tower, the pairs in `PAIRS` check it against dropping every rock.
+pub struct Day17Optimized<const C: usize>;
+
+const TOWER_SIZE: usize = 4 * 1024;
+const TOWER_KEEP: usize = 512;
+const TOWER_ALMOSTFULL: isize = (TOWER_SIZE - 10) as isize;
+const TOWER_DELETE: usize = TOWER_SIZE - TOWER_KEEP;
+
+/// The tower one rock at a time, each row a byte with a bit per column. Only the top of it is
+/// kept, what was cut off below is counted in `height_below`.
+#[derive(Clone)]
+pub struct Tower<'a> {
+    jets: &'a [bool],
+    jet: usize,
+    rock: usize,
+    rocks: [u32; 5],
+    heights: [isize; 5],
+    tower: Vec<u8>,
+    first_free_row: isize,
+    height_below: i64,
+}
+
+impl<'a> Tower<'a> {
+    pub fn new(jets: &'a [bool]) -> Self {
+        let mut tower = vec![0; TOWER_SIZE];
+        tower[0] = 127;
+        Self {
+            jets,
+            jet: 0,
+            rock: 0,
+            rocks: [0, 1, 2, 3, 4].map(|t| Rock::construct_mask(t) << 2),
+            heights: [0, 1, 2, 3, 4].map(|t| Rock::height(t) as isize),
+            tower,
+            first_free_row: 1,
+            // The floor is row 0.
+            height_below: -1,
+        }
+    }
+
+    pub fn height(&self) -> i64 {
+        self.height_below.saturating_add(self.first_free_row as i64)
+    }
+
+    fn next_jet(&mut self) -> bool {
+        let jet = self.jets[self.jet];
+        self.jet = (self.jet + 1) % self.jets.len();
+        jet
+    }
+
+    /// Four rows of the tower from `row` up, a byte each like the rock masks.
+    fn rows(&self, row: isize) -> u32 {
+        let row = row as usize;
+        u32::from_le_bytes(self.tower[row..row + 4].try_into().unwrap())
+    }
+
+    fn place(&mut self, row: isize, rock: u32) {
+        let rows = self.tower[row as usize..].iter_mut();
+        for (byte, rock_row) in rows.zip(rock.to_le_bytes()) {
+            *byte |= rock_row;
+        }
+    }
+
+    /// The free cells a rock could still get to, row by row down from the top, found by
+    /// spreading down and sideways from the empty rows above. Rocks never move up, so the rest of
+    /// the tower can never be reached and is as good as full. `None` if the cells go on below
+    /// what is kept of the tower.
+    fn surface(&self) -> Option<Vec<u8>> {
+        let mut surface = vec![];
+        let mut reachable = 0x7f;
+        for row in (0..self.first_free_row as usize).rev() {
+            let free = !self.tower[row] & 0x7f;
+            reachable &= free;
+            loop {
+                let spread = reachable | ((reachable << 1 | reachable >> 1) & free);
+                if spread == reachable {
+                    break;
+                }
+                reachable = spread;
+            }
+            if reachable == 0 {
+                return Some(surface);
+            }
+            surface.push(reachable);
+        }
+        None
+    }
+}
+
+impl Simulation for Tower<'_> {
+    /// The next rock and jet and the surface of the tower, all that decides where rocks land.
+    type Fingerprint = (usize, usize, Vec<u8>);
+
+    fn step(&mut self) {
+        let left_side = Rock::left_side_mask();
+        let right_side = Rock::right_side_mask();
+
+        let mut rock = self.rocks[self.rock];
+        let height = self.heights[self.rock];
+        self.rock = (self.rock + 1) % 5;
+
+        let mut row = self.first_free_row;
+
+        if self.next_jet() {
+            rock <<= 1;
+        } else {
+            rock >>= 1;
+        }
+
+        if self.next_jet() {
+            if rock & right_side == 0 {
+                rock <<= 1;
+            }
+        } else {
+            // NOTE(lubo): Collision not possible on the left side yet.
+            rock >>= 1;
+        }
+
+        for _ in 0..2 {
+            if self.next_jet() {
+                if rock & right_side == 0 {
+                    rock <<= 1;
+                }
+            } else {
+                #[allow(clippy::collapsible_else_if)]
+                if rock & left_side == 0 {
+                    rock >>= 1;
+                }
+            }
+        }
+
+        loop {
+            // NOTE(lubo): First possible collision
+            row -= 1;
+
+            if self.rows(row) & rock != 0 {
+                row += 1;
+                self.place(row, rock);
+                self.first_free_row = std::cmp::max(self.first_free_row, row + height);
+
+                if self.first_free_row > TOWER_ALMOSTFULL {
+                    self.first_free_row -= TOWER_DELETE as isize;
+                    self.height_below += TOWER_DELETE as i64;
+
+                    self.tower.copy_within(TOWER_DELETE.., 0);
+                    self.tower[TOWER_KEEP..].fill(0);
+                }
+
+                break;
+            }
+
+            let w = self.next_jet();
+            if w {
+                if rock & right_side == 0 {
+                    rock <<= 1;
+                }
+            } else {
+                #[allow(clippy::collapsible_else_if)]
+                if rock & left_side == 0 {
+                    rock >>= 1;
+                }
+            }
+
+            if self.rows(row) & rock != 0 {
+                match w {
+                    true => rock >>= 1,
+                    false => rock <<= 1,
+                };
+            }
+        }
+    }
+
+    fn fingerprint(&self) -> Option<Self::Fingerprint> {
+        Some((self.rock, self.jet, self.surface()?))
+    }
+
+    fn fast_forward(&mut self, earlier: &Self, cycles: u64) {
+        let gained = self.height() - earlier.height();
+        debug!("Skipping {} cycles, each {} rows higher", cycles, gained);
+        // Saturate instead of wrapping around to a negative height.
+        let skipped = i64::try_from(cycles)
+            .ok()
+            .and_then(|cycles| gained.checked_mul(cycles))
+            .unwrap_or(i64::MAX);
+        self.height_below = self.height_below.saturating_add(skipped);
+    }
+}
+
+impl<const C: usize> Problem for Day17Optimized<C> {
+    type Input = Vec<bool>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        parse_jets(reader)
+    }
+
+    fn part1(jets: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut tower = Tower::new(jets);
+        let mut ticker = config.cancel().ticker();
+        tower.try_run_n_with(C as u64, |_| {
+            ticker.tick();
+            ticker.check()
+        })?;
+        debug!("{}", tower.height());
+        Ok(tower.height().into())
+    }
+}
+
+/// `Day17Optimized` dropping every rock, to check the cycles it skips against.
+pub struct Day17Plain<const C: usize>;
+
+impl<const C: usize> Problem for Day17Plain<C> {
+    type Input = Vec<bool>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        parse_jets(reader)
+    }
+
+    fn part1(jets: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let mut tower = Tower::new(jets);
+        for _ in 0..C {
+            tower.step();
+        }
+        Ok(tower.height().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JETS: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn skipping_cycles_matches_dropping_every_rock() {
+        let jets = parse_jets(BufReader::new(JETS.as_bytes())).unwrap();
+        let mut plain = Tower::new(&jets);
+        for n in 1..=10_000u64 {
+            plain.step();
+            if n % 1_111 == 0 || n == 2022 {
+                let mut skipping = Tower::new(&jets);
+                skipping.run_n(n);
+                assert_eq!(skipping.height(), plain.height(), "after {} rocks", n);
+            }
+        }
+
+        let mut tower = Tower::new(&jets);
+        tower.run_n(1_000_000_000_000);
+        assert_eq!(tower.height(), 1_514_285_714_288);
+    }
+}
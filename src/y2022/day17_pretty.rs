@@ -0,0 +1,184 @@
+use std::io::BufReader;
+
+use crate::{
+    cancel::Cancel,
+    debug,
+    frames::Recorder,
+    simulation::Simulation,
+    trace,
+    y2022::{
+        day17::{parse_jets, Rock},
+        day17_optimized::Tower,
+    },
+    Answer, Config, Day, Error, Problem,
+};
+use lk_math::{
+    prelude::*,
+    cli::Progress,
+    explore::{Exploration, ExploreSignals},
+    sketch::StackBag,
+};
+
+/// Height of the tower after `C` rocks have come to rest. The top of the tower is recorded
+/// after every rock.
+fn tower_height<const C: usize>(
+    jets: &[bool],
+    recorder: &mut Recorder,
+    cancel: &Cancel,
+) -> Result<u64, Error> {
+    let check = |map: &Array2d<char>, blueprint: &Vec<_>, p| {
+        blueprint
+            .iter()
+            .map(|line: &LineV2i32| {
+                map.iter_values_in_line::<true>(line.start + p, line.end + p)
+                    .all(|c| c == &'.')
+            })
+            .all(|x| x)
+    };
+
+    let draw = |map: &mut Array2d<char>, blueprint: &Vec<LineV2i32>, p| {
+        blueprint.iter().for_each(|line| {
+            map.draw_line::<true>(line.offset(p), '#');
+        })
+    };
+
+    let wind = jets
+        .iter()
+        .map(|&right| if right { 1 } else { -1 })
+        .collect::<Vec<_>>();
+    let wind_count = wind.len();
+    debug!("Wind length: {}", wind_count);
+
+    let mut wind = wind.iter().cycle();
+
+    let mut map = Array2d::with_dimensions(7, 100, '.');
+
+    let mut progress = Progress::new(C);
+    let mut first_free_row_absolute = 0u64;
+    let mut first_free_row = 0;
+    let mut first_non_full_row = 0;
+    for (iteration, rock_type) in (0..5).cycle().enumerate().take(C) {
+        cancel.check()?;
+        let mut p = V2::from_xy(2, first_free_row + 3);
+        let blueprint = Rock::construct(rock_type);
+        let width = Rock::width(rock_type);
+        let height = Rock::height(rock_type);
+
+        progress.progress(iteration);
+
+        // Rows are only cut off below the first one that can't be reached, so a deep
+        // enough shaft can outgrow the window. Double it then.
+        if first_free_row + 3 + height > map.height() as i32 {
+            let mut bigger = Array2d::with_dimensions(7, 2 * map.height(), '.');
+            for y in 0..map.height() as i32 {
+                for x in 0..7 {
+                    let q = V2::from_xy(x, y);
+                    bigger.set(q, *map.get(q).unwrap());
+                }
+            }
+            map = bigger;
+        }
+
+        assert!(check(&map, &blueprint, p));
+
+        loop {
+            let x = (p.x() + *wind.next().unwrap()).clamp(0, 7 - width);
+            if p.x() != x && check(&map, &blueprint, V2::from_xy(x, p.y())) {
+                p.values[0] = x;
+            }
+
+            if p.y() > 0 && check(&map, &blueprint, V2::from_xy(p.x(), p.y() - 1)) {
+                p.values[1] -= 1;
+            } else {
+                draw(&mut map, &blueprint, p);
+                first_free_row = std::cmp::max(first_free_row, p.y() + height);
+
+                loop {
+                    let a = map
+                        .line_iter::<true>(
+                            V2::from_xy(0, first_non_full_row),
+                            V2::from_xy(6, first_non_full_row),
+                        )
+                        .find(|&x| map.get(x).unwrap() == &'.');
+
+                    if let Some(a) = a {
+                        let mut failed = false;
+                        let mut exp = Exploration::new(map, ());
+                        exp.explore::<_, _, StackBag<_>>(
+                            a,
+                            |x, map, _| {
+                                if x.y() >= first_free_row {
+                                    failed = true;
+                                    return ExploreSignals::ReachedGoal;
+                                }
+                                map.set(*x, '@');
+                                ExploreSignals::Explore
+                            },
+                            |_p, x, map, _| x.y() <= first_free_row && map.get(*x).unwrap() == &'.',
+                        );
+                        // NOTE(lubo): Exploration returns the map
+                        map = exp.context;
+
+                        trace!("{}", map);
+
+                        if failed {
+                            map.replace_all(&'@', &'.');
+                            break;
+                        } else {
+                            map.replace_all(&'@', &'X');
+                        }
+                    } else {
+                        first_non_full_row += 1;
+                    }
+                }
+
+                map.shift_n_rows_down(first_non_full_row.try_into().unwrap(), '.');
+                first_free_row_absolute +=
+                    <i32 as std::convert::TryInto<u64>>::try_into(first_non_full_row).unwrap();
+                first_free_row -= first_non_full_row;
+                first_non_full_row = 0;
+
+                break;
+            }
+        }
+
+        recorder.step(|| map.to_string());
+    }
+
+    first_free_row_absolute +=
+        <i32 as std::convert::TryInto<u64>>::try_into(first_free_row).unwrap();
+
+    debug!("{}", first_free_row_absolute);
+
+    Ok(first_free_row_absolute)
+}
+
+impl Problem for Day<17> {
+    /// Jet directions, `true` pushing right.
+    type Input = Vec<bool>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        parse_jets(reader)
+    }
+
+    fn part1(jets: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut recorder = Recorder::from_config(config)?;
+        let height = tower_height::<2022>(jets, &mut recorder, config.cancel())?;
+        recorder.finish()?;
+        Answer::try_from(height)
+    }
+
+    // Far too many rocks to drop one by one, this relies on the tower repeating.
+    fn part2(jets: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut tower = Tower::new(jets);
+        let mut ticker = config.cancel().ticker();
+        tower.try_run_n_with(1_000_000_000_000, |_| {
+            ticker.tick();
+            ticker.check()
+        })?;
+        Ok(tower.height().into())
+    }
+}
@@ -0,0 +1,157 @@
+use std::{collections::HashSet, io::prelude::*, io::BufReader, str::FromStr};
+
+use crate::{
+    error::ParseError,
+    image::{self, Image, Rgb},
+    info,
+    search::CountedExploration,
+    Answer, Config, Day, Error, Problem,
+};
+use lk_math::{
+    explore::{Exploration, ExploreSignals},
+    prelude::*,
+    sketch::StackBag,
+    vector::Scalar,
+};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+enum Normals {
+    X,
+    Y,
+    Z,
+}
+
+fn parse_cubes<T>(reader: BufReader<T>) -> Result<Vec<V3<i32>>, Error>
+where
+    T: std::io::Read,
+{
+    let mut cubes = vec![];
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let v = V3::<i32>::from_str(&line)
+            .map_err(|_| ParseError::at(index + 1, &line, &line, "coordinates like '2,2,2'"))?;
+        cubes.push(v);
+    }
+    Ok(cubes)
+}
+
+/// Every `z` slice of the droplet side by side, `dim` being the size of `map`. Lava is orange,
+/// the air reached from outside dark blue and the air trapped inside light blue.
+fn draw_slices(map: &Array3d<char>, dim: V3<i32>) -> Image {
+    let (width, height, depth) = (dim.x() as usize, dim.y() as usize, dim.z() as usize);
+    let columns = (depth as f32).sqrt().ceil() as usize;
+    let rows = depth.div_ceil(columns);
+    let mut image = Image::new(columns * (width + 1), rows * (height + 1), Rgb::BLACK);
+    for z in 0..depth {
+        let mut slice = Image::new(width, height, Rgb::BLACK);
+        for y in 0..height {
+            for x in 0..width {
+                let color = match map.get(V3::from_xyz(x as i32, y as i32, z as i32)) {
+                    Some('#') => Rgb(240, 110, 30),
+                    Some('o') => Rgb(20, 30, 70),
+                    _ => Rgb(120, 200, 255),
+                };
+                slice.set(x, y, color);
+            }
+        }
+        image.blit(
+            &slice,
+            z % columns * (width + 1),
+            z / columns * (height + 1),
+        );
+    }
+    image
+}
+
+impl Problem for Day<18> {
+    type Input = Vec<V3<i32>>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        parse_cubes(reader)
+    }
+
+    fn part1(cubes: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let mut open_sides = 0;
+        let mut sides = HashSet::new();
+
+        for v in cubes {
+            let x = v.x();
+            let y = v.y();
+            let z = v.z();
+
+            let mut g = |x| {
+                if sides.contains(&x) {
+                    open_sides -= 1;
+                } else {
+                    open_sides += 1;
+                    sides.insert(x);
+                }
+            };
+
+            g((x, y, z, Normals::X));
+            g((x, y, z, Normals::Y));
+            g((x, y, z, Normals::Z));
+            g((x + 1, y, z, Normals::X));
+            g((x, y + 1, z, Normals::Y));
+            g((x, y, z + 1, Normals::Z));
+        }
+
+        Ok(open_sides.into())
+    }
+
+    fn part2(cubes: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut occ = HashSet::new();
+        let mut aabb: Option<Aabb<3, _>> = None;
+
+        for &v in cubes {
+            occ.insert(v);
+
+            match aabb.as_mut() {
+                Some(aabb) => aabb.cover(&v),
+                None => aabb = Some(Aabb::new(v, v)),
+            }
+        }
+
+        let aabb = aabb.ok_or_else(|| Error::NoSolution("no lava cubes".into()))?;
+        let min = aabb.min;
+        let dim = aabb.dim() + V3::from_xyz(1, 1, 1) + Scalar::new(2) * V3::from_xyz(1, 1, 1);
+
+        let unknown = '.';
+        let lava = '#';
+        let outside = 'o';
+
+        let mut map = Array3d::new(dim.values, unknown);
+        for x in occ.iter() {
+            map.set(*x - min + V3::from_xyz(1, 1, 1), lava);
+        }
+
+        // NOTE(lubo): We know this point is outside. (It lies on the 1 block boundary we created)
+        let start = V3::from_xyz(0, 0, 0);
+
+        let mut result = 0;
+        let mut exp = Exploration::new(map, ());
+        let stats = exp.explore_avoid_identical_counted::<_, _, StackBag<_>>(
+            start,
+            |a, map, _| {
+                debug_assert_ne!(map.get(*a).unwrap(), &lava);
+                map.set(*a, outside);
+                ExploreSignals::Explore
+            },
+            |_p, a, map, _| {
+                let v = map.get(*a).unwrap();
+                if v == &lava {
+                    result += 1;
+                }
+                v == &unknown
+            },
+        );
+        info!("{}", stats);
+
+        image::export(config, || draw_slices(&exp.context, dim))?;
+
+        Ok(result.into())
+    }
+}
@@ -1,6 +1,9 @@
-use std::{io::prelude::*, io::BufReader, str::FromStr};
+use std::{io::prelude::*, io::BufReader};
 
-use crate::Problem;
+use crate::{
+    cancel::Cancel, debug, error::ParseError, generate::Rng, info, pattern::Pattern,
+    search::CountedExploration, trace, Answer, Config, Day, Error, Problem,
+};
 use lk_math::{
     prelude::*,
     explore::{Exploration, ExploreSignals},
@@ -12,7 +15,8 @@ type Clay = i32;
 type Obsidian = i32;
 type Geode = i32;
 
-struct Blueprint {
+#[derive(Clone)]
+pub struct Blueprint {
     id: i32,
     ore_r_cost: Ore,
     clay_r_cost: Ore,
@@ -22,25 +26,28 @@ struct Blueprint {
     geode_r_cost_obs: Obsidian,
 }
 
-impl FromStr for Blueprint {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_a, s) = s.split_once("Blueprint").ok_or(())?;
-        let (a, s) = s.split_once(": Each ore robot costs").ok_or(())?;
-        let id = a.trim().parse().or(Err(()))?;
-        let (a, s) = s.split_once("ore. Each clay robot costs").ok_or(())?;
-        let ore_r_cost = a.trim().parse().or(Err(()))?;
-        let (a, s) = s.split_once("ore. Each obsidian robot costs").ok_or(())?;
-        let clay_r_cost = a.trim().parse().or(Err(()))?;
-        let (a, s) = s.split_once("ore and").ok_or(())?;
-        let obs_r_cost_ore = a.trim().parse().or(Err(()))?;
-        let (a, s) = s.split_once("clay. Each geode robot costs").ok_or(())?;
-        let obs_r_cost_clay = a.trim().parse().or(Err(()))?;
-        let (a, s) = s.split_once("ore and").ok_or(())?;
-        let geode_r_cost_ore = a.trim().parse().or(Err(()))?;
-        let (a, _s) = s.split_once("obsidian.").ok_or(())?;
-        let geode_r_cost_obs = a.trim().parse().or(Err(()))?;
+impl Blueprint {
+    fn parse(line: usize, text: &str) -> Result<Self, ParseError> {
+        const BLUEPRINT: Pattern = Pattern::new(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+        );
+
+        let fields = BLUEPRINT.capture(line, text)?;
+        // Costs end up as divisors, so zero is rejected too.
+        let number = |index| match fields.parse::<i32>(index, "a positive number") {
+            Ok(x) if x > 0 => Ok(x),
+            _ => Err(fields.error(index, "a positive number")),
+        };
+
+        let id = number(0)?;
+        let ore_r_cost = number(1)?;
+        let clay_r_cost = number(2)?;
+        let obs_r_cost_ore = number(3)?;
+        let obs_r_cost_clay = number(4)?;
+        let geode_r_cost_ore = number(5)?;
+        let geode_r_cost_obs = number(6)?;
 
         Ok(Self {
             id,
@@ -53,10 +60,17 @@ impl FromStr for Blueprint {
         })
     }
 }
-impl IterateNeighboursContext for Blueprint {}
+
+/// A blueprint and how many minutes the robots have to work with it.
+struct Factory {
+    blueprint: Blueprint,
+    minutes: i32,
+}
+
+impl IterateNeighboursContext for Factory {}
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Clone, Copy)]
-struct Point<const C: i32> {
+struct Point {
     time: i32,
 
     ore: Ore,
@@ -70,7 +84,7 @@ struct Point<const C: i32> {
     geode_r: i32,
 }
 
-impl<const C: i32> Point<C> {
+impl Point {
     fn wait(&self) -> Self {
         let mut result = *self;
 
@@ -156,16 +170,17 @@ impl<const C: i32> Point<C> {
     }
 }
 
-impl<const C: i32> IterateNeighbours<Blueprint> for Point<C> {
-    fn neighbours(&self, bp: &Blueprint) -> Vec<Self> {
-        if self.time >= C {
+impl IterateNeighbours<Factory> for Point {
+    fn neighbours(&self, factory: &Factory) -> Vec<Self> {
+        let (bp, minutes) = (&factory.blueprint, factory.minutes);
+        if self.time >= minutes {
             return vec![];
         }
-        if self.time >= C - 1 {
+        if self.time >= minutes - 1 {
             // NOTE(lubo): Even if we started building a geobot, it wouldn't have time to produce any geodes.
             return vec![self.wait()];
         }
-        if self.time >= C - 2 {
+        if self.time >= minutes - 2 {
             // NOTE(lubo): Last change to build a geobot that will produce 1 geode.
             if self.can_buy_geobot(bp) {
                 return vec![self.buy_geobot(bp).unwrap().wait()];
@@ -177,7 +192,7 @@ impl<const C: i32> IterateNeighbours<Blueprint> for Point<C> {
         let mut working = 0b1111;
         let mut results = [None; 4];
 
-        let time_left = C - self.time;
+        let time_left = minutes - self.time;
         let bots = self.can_buy_orebots(bp);
         if bots >= time_left {
             working &= 0b1110;
@@ -197,7 +212,7 @@ impl<const C: i32> IterateNeighbours<Blueprint> for Point<C> {
 
         let mut probe = *self;
 
-        for _time in self.time..C {
+        for _time in self.time..minutes {
             if (working & 0b0001) > 0 && probe.can_buy_orebot(bp) {
                 working &= 0b1110;
                 results[0] = Some(probe.buy_orebot(bp).unwrap());
@@ -221,7 +236,7 @@ impl<const C: i32> IterateNeighbours<Blueprint> for Point<C> {
             probe = probe.wait();
         }
 
-        let time_left = C - self.time;
+        let time_left = minutes - self.time;
         if results[0].is_some() {
             let bots = results[0].unwrap().can_buy_orebots(bp);
             if bots >= time_left {
@@ -253,7 +268,7 @@ impl<const C: i32> IterateNeighbours<Blueprint> for Point<C> {
             && results[3].is_none()
         {
             let mut a = *self;
-            while a.time < C {
+            while a.time < minutes {
                 a = a.wait();
             }
             return vec![a];
@@ -262,62 +277,100 @@ impl<const C: i32> IterateNeighbours<Blueprint> for Point<C> {
         results
             .into_iter()
             .flatten()
-            .filter(|x| x.time <= C)
+            .filter(|x| x.time <= minutes)
             .collect::<Vec<_>>()
     }
 }
 
-pub struct Day19<const C: i32, const B: bool>;
+/// Most geodes the blueprint can crack open in the given number of minutes.
+fn max_geodes(blueprint: &Blueprint, minutes: i32, cancel: &Cancel) -> Result<i32, Error> {
+    let factory = Factory {
+        blueprint: blueprint.clone(),
+        minutes,
+    };
+    let mut exp = Exploration::new(factory, ());
+    let mut max_geodes_for_bp = 0;
+    let mut ticker = cancel.ticker();
+    let stats = exp.explore_counted::<_, _, StackBag<_>>(
+        Point {
+            ore_r: 1,
+            ..Default::default()
+        },
+        |p, _factory, _| {
+            if ticker.tick() {
+                return ExploreSignals::ReachedGoal;
+            }
+            if p.geode > max_geodes_for_bp {
+                max_geodes_for_bp = p.geode;
+                trace!("new best {:?}", p);
+            }
+
+            ExploreSignals::Explore
+        },
+        |_p, _n, _factory, _| true,
+    );
+    info!("{}", stats);
+    debug!(
+        "Bp {} collected {} geodes.",
+        blueprint.id, max_geodes_for_bp
+    );
+    ticker.check()?;
+    Ok(max_geodes_for_bp)
+}
+
+impl Problem for Day<19> {
+    type Input = Vec<Blueprint>;
 
-impl<const C: i32, const B: bool> Problem for Day19<C, B> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        let lines = reader.lines().map(|x| x.unwrap()).collect::<Vec<_>>();
-        let blueprints = lines
-            .iter()
-            .map(|x| Blueprint::from_str(x).unwrap())
-            .collect::<Vec<_>>();
+        let mut blueprints = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            blueprints.push(Blueprint::parse(index + 1, &line)?);
+        }
+        Ok(blueprints)
+    }
 
-        let blueprints = if B {
-            blueprints.into_iter().take(3).collect()
-        } else {
-            blueprints
-        };
+    fn part1(blueprints: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let minutes = config.get("minutes", 24)?;
+        let result = blueprints
+            .iter()
+            .map(|blueprint| Ok(blueprint.id * max_geodes(blueprint, minutes, config.cancel())?))
+            .sum::<Result<i32, Error>>()?;
+        Ok(result.into())
+    }
 
-        let mut result = i32::from(B);
-
-        for blueprint in blueprints {
-            let mut exp = Exploration::new(blueprint, ());
-            let mut max_geodes_for_bp = 0;
-            exp.explore::<_, _, StackBag<_>>(
-                Point::<C> {
-                    ore_r: 1,
-                    ..Default::default()
-                },
-                |p, _bp, _| {
-                    if p.geode > max_geodes_for_bp {
-                        max_geodes_for_bp = p.geode;
-                        println!("new best {:?}", p);
-                    }
-
-                    ExploreSignals::Explore
-                },
-                |_p, _n, _bp, _| true,
-            );
-            if B {
-                result *= max_geodes_for_bp;
-            } else {
-                result += exp.context.id * max_geodes_for_bp;
-            }
-            println!(
-                "Bp {} collected {} geodes.   Result so far: {}",
-                exp.context.id, max_geodes_for_bp, result
-            );
-        }
+    fn part2(blueprints: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let minutes = config.get("minutes", 32)?;
+        // The elephants ate the rest of the blueprints.
+        let count = config.get("blueprints", 3)?;
+        let result = blueprints
+            .iter()
+            .take(count)
+            .map(|blueprint| max_geodes(blueprint, minutes, config.cancel()))
+            .product::<Result<i32, Error>>()?;
+        Ok(result.into())
+    }
+}
 
-        writeln!(writer, "{}", result).unwrap();
+/// `size` blueprints with costs in the ranges the real ones use.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for id in 1..=size.max(1) {
+        text.push_str(&format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.\n",
+            id,
+            rng.range(2..=4),
+            rng.range(2..=4),
+            rng.range(2..=4),
+            rng.range(4..=20),
+            rng.range(2..=4),
+            rng.range(4..=20)
+        ));
     }
+    text
 }
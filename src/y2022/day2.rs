@@ -0,0 +1,150 @@
+use std::{io::prelude::*, io::BufReader};
+
+use crate::{error::ParseError, Answer, Config, Day, Error, Outcome, Problem};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Hands {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl PartialOrd for Hands {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match self {
+            Hands::Rock => match other {
+                Hands::Rock => Some(std::cmp::Ordering::Equal),
+                Hands::Paper => Some(std::cmp::Ordering::Less),
+                Hands::Scissors => Some(std::cmp::Ordering::Greater),
+            },
+            Hands::Paper => match other {
+                Hands::Rock => Some(std::cmp::Ordering::Greater),
+                Hands::Paper => Some(std::cmp::Ordering::Equal),
+                Hands::Scissors => Some(std::cmp::Ordering::Less),
+            },
+            Hands::Scissors => match other {
+                Hands::Rock => Some(std::cmp::Ordering::Less),
+                Hands::Paper => Some(std::cmp::Ordering::Greater),
+                Hands::Scissors => Some(std::cmp::Ordering::Equal),
+            },
+        }
+    }
+}
+
+fn opp_hand(x: &str) -> Option<Hands> {
+    match x {
+        "A" => Some(Hands::Rock),
+        "B" => Some(Hands::Paper),
+        "C" => Some(Hands::Scissors),
+        _ => None,
+    }
+}
+fn my_hand(x: &str) -> Option<Hands> {
+    match x {
+        "X" => Some(Hands::Rock),
+        "Y" => Some(Hands::Paper),
+        "Z" => Some(Hands::Scissors),
+        _ => None,
+    }
+}
+fn hand_needed_for_outcome(opp: Hands, outcome: Outcome) -> Hands {
+    match opp {
+        Hands::Rock => match outcome {
+            Outcome::Loss => Hands::Scissors,
+            Outcome::Draw => Hands::Rock,
+            Outcome::Win => Hands::Paper,
+        },
+        Hands::Paper => match outcome {
+            Outcome::Loss => Hands::Rock,
+            Outcome::Draw => Hands::Paper,
+            Outcome::Win => Hands::Scissors,
+        },
+        Hands::Scissors => match outcome {
+            Outcome::Loss => Hands::Paper,
+            Outcome::Draw => Hands::Scissors,
+            Outcome::Win => Hands::Rock,
+        },
+    }
+}
+
+fn hand_score(hand: Hands) -> i32 {
+    match hand {
+        Hands::Rock => 1,
+        Hands::Paper => 2,
+        Hands::Scissors => 3,
+    }
+}
+
+fn outcome_score(hand1: Hands, hand2: Hands) -> i32 {
+    match hand1.partial_cmp(&hand2) {
+        Some(order) => match order {
+            std::cmp::Ordering::Less => 0,
+            std::cmp::Ordering::Equal => 3,
+            std::cmp::Ordering::Greater => 6,
+        },
+        None => panic!(),
+    }
+}
+
+impl Problem for Day<2> {
+    /// The opponent's hand and the second column, read as our hand.
+    type Input = Vec<(Hands, Hands)>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        assert!(Hands::Paper < Hands::Scissors);
+        assert!(Hands::Scissors < Hands::Rock);
+        assert!(Hands::Rock < Hands::Paper);
+
+        let mut rounds = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let vec: Vec<&str> = line.split_ascii_whitespace().collect();
+            match vec[..] {
+                [first, second] => {
+                    let opp = opp_hand(first).ok_or_else(|| {
+                        ParseError::at(index + 1, &line, first, "'A', 'B' or 'C'")
+                    })?;
+                    let me = my_hand(second).ok_or_else(|| {
+                        ParseError::at(index + 1, &line, second, "'X', 'Y' or 'Z'")
+                    })?;
+                    rounds.push((opp, me));
+                }
+                [] => continue,
+                _ => {
+                    return Err(
+                        ParseError::at(index + 1, &line, &line, "two hands like 'A Y'").into(),
+                    )
+                }
+            }
+        }
+        Ok(rounds)
+    }
+
+    fn part1(rounds: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let total_score: i32 = rounds
+            .iter()
+            .map(|&(opp, me)| hand_score(me) + outcome_score(me, opp))
+            .sum();
+        Ok(total_score.into())
+    }
+
+    fn part2(rounds: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        let total_score: i32 = rounds
+            .iter()
+            .map(|&(opp, second)| {
+                // The second column is actually the outcome we need: X loses, Y draws, Z wins.
+                let needed_outcome = match second {
+                    Hands::Rock => Outcome::Loss,
+                    Hands::Paper => Outcome::Draw,
+                    Hands::Scissors => Outcome::Win,
+                };
+                let needed_hand = hand_needed_for_outcome(opp, needed_outcome);
+                hand_score(needed_hand) + outcome_score(needed_hand, opp)
+            })
+            .sum();
+        Ok(total_score.into())
+    }
+}
@@ -0,0 +1,132 @@
+use std::{io::prelude::*, io::BufReader};
+
+use crate::{
+    debug,
+    error::{parse_field, ParseError},
+    trace, Answer, Config, Day, Error, Problem,
+};
+use lk_math::{
+    bijection::Bijection,
+    modular::{ModularAddAssign, ModularDecompose, ModularSubAssign},
+};
+
+#[derive(Debug, Clone)]
+struct TangleArray<T> {
+    array: Vec<T>,
+    bijection: Bijection,
+}
+
+impl<T> TangleArray<T> {
+    pub fn new(array: Vec<T>) -> Self {
+        Self {
+            bijection: Bijection::new(array.len()),
+            array,
+        }
+    }
+
+    pub fn get_untangled_element(&self, index: usize) -> &T {
+        &self.array[index]
+    }
+
+    pub fn get_tangled_element(&self, index: usize) -> &T {
+        &self.array[self.bijection.g[index]]
+    }
+}
+
+impl<T: Copy> TangleArray<T> {
+    pub fn collect_tangled(&self) -> Vec<T> {
+        let len = self.array.len();
+        (0..len).map(|x| *self.get_tangled_element(x)).collect()
+    }
+}
+
+/// Mixes the file `mixes` times with every number multiplied by decryption `key` and sums the
+/// grove coordinates.
+fn grove_coordinates(nums: &[i32], mixes: usize, key: i32) -> i64 {
+    let len = nums.len();
+
+    let mut a = TangleArray::new(nums.to_vec());
+
+    let mut global_offset = 0;
+
+    for _ in 0..mixes {
+        for i in 0..len {
+            let mut t = *a.get_untangled_element(i);
+            trace!("Picked {} from list!", t);
+
+            let len_minus_1: i32 = (len - 1).try_into().unwrap();
+            let decrypt = key % len_minus_1;
+            t *= decrypt;
+
+            let accepted_upper_bound = (len_minus_1 + 1) / 2;
+            let accepted_lower_bound = -accepted_upper_bound;
+            while t < accepted_lower_bound {
+                t += len_minus_1;
+            }
+            while t > accepted_upper_bound {
+                t -= len_minus_1;
+            }
+
+            if t > 0 {
+                for x in 0..t {
+                    a.bijection.swap_with_right(i);
+                    if a.bijection.f[(x as usize).mod_n(len)] == len - 1 {
+                        global_offset.subassign_n(1, len);
+                    }
+                }
+            } else {
+                for x in 0..-t {
+                    a.bijection.swap_with_left(i);
+                    if a.bijection.f[(x as usize).mod_n(len)] == 1 {
+                        global_offset.addassign_n(1, len);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut deciphered = a.collect_tangled();
+    deciphered.rotate_right(global_offset);
+    let index_of_zero = deciphered.iter().position(|x| *x == 0).unwrap();
+    let a = deciphered[(index_of_zero + 1000) % deciphered.len()];
+    let b = deciphered[(index_of_zero + 2000) % deciphered.len()];
+    let c = deciphered[(index_of_zero + 3000) % deciphered.len()];
+    let a = a as i64 * key as i64;
+    let b = b as i64 * key as i64;
+    let c = c as i64 * key as i64;
+    debug!("{} + {} + {} = {}", a, b, c, a + b + c);
+
+    a + b + c
+}
+
+impl Problem for Day<20> {
+    /// The encrypted file.
+    type Input = Vec<i32>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut nums = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            nums.push(parse_field::<i32>(index + 1, &line, &line, "a number")?);
+        }
+        if !nums.contains(&0) {
+            return Err(ParseError::missing("a zero").into());
+        }
+        Ok(nums)
+    }
+
+    fn part1(nums: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mixes = config.get("mixes", 1)?;
+        let key = config.get("key", 1)?;
+        Ok(grove_coordinates(nums, mixes, key).into())
+    }
+
+    fn part2(nums: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mixes = config.get("mixes", 10)?;
+        let key = config.get("key", 811589153)?;
+        Ok(grove_coordinates(nums, mixes, key).into())
+    }
+}
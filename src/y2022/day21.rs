@@ -0,0 +1,115 @@
+use std::{collections::HashMap, io::prelude::*, io::BufReader};
+
+use crate::{
+    debug,
+    dot::{self, Graph},
+    error::ParseError,
+    Answer, Config, Day, Error, Problem,
+};
+use lk_math::expr::Expr;
+
+/// Every monkey's job. Root's job is also kept as text, part 2 reads it as an equation instead.
+pub struct Monkeys {
+    jobs: HashMap<String, Expr<i64>>,
+    root: String,
+    /// Every monkey's name and job as written, in input order.
+    listing: Vec<(String, String)>,
+}
+
+impl Monkeys {
+    /// Every monkey with its number or operation, and an edge to each monkey it waits for.
+    pub fn draw(&self) -> Graph {
+        let mut graph = Graph::directed();
+        for (name, job) in &self.listing {
+            match job.split_whitespace().collect::<Vec<_>>()[..] {
+                [a, op, b] => {
+                    graph.node(name, &format!("{}\n{}", name, op));
+                    graph.edge(name.as_str(), a);
+                    graph.edge(name.as_str(), b);
+                }
+                _ => graph.node_with(name, &format!("{}\n{}", name, job.trim()), "shape=box"),
+            }
+        }
+        graph
+    }
+}
+
+impl Problem for Day<21> {
+    type Input = Monkeys;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut jobs = HashMap::new();
+        let mut root = None;
+        let mut listing = vec![];
+        let mut operands = vec![];
+
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+        for (index, line) in lines.iter().enumerate() {
+            let (a, b) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(index + 1, line, line, "'name: job'"))?;
+            let expr = b.parse().map_err(|_| {
+                ParseError::at(index + 1, line, b.trim(), "a number or an operation")
+            })?;
+            if a == "root" {
+                root = Some(b.to_string());
+            }
+            if let [left, _, right] = b.split_whitespace().collect::<Vec<_>>()[..] {
+                operands.extend([(index, left), (index, right)]);
+            }
+            jobs.insert(a.into(), expr);
+            listing.push((a.to_string(), b.to_string()));
+        }
+
+        let root = root.ok_or_else(|| ParseError::missing("a monkey named 'root'"))?;
+        // Checked once every monkey is known, a job can name one further down.
+        for (index, name) in operands {
+            if !jobs.contains_key(name) {
+                let line = &lines[index];
+                return Err(ParseError::at(index + 1, line, name, "a monkey in the input").into());
+            }
+        }
+        Ok(Monkeys {
+            jobs,
+            root,
+            listing,
+        })
+    }
+
+    fn part1(monkeys: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        dot::export(config, || monkeys.draw())?;
+        let jobs = &monkeys.jobs;
+        let result = jobs["root"].eval(jobs).map_err(|_| {
+            Error::NoSolution("the jobs of 'root' do not add up to a number".into())
+        })?;
+        debug!("Result {}", result);
+        Ok(result.into())
+    }
+
+    fn part2(monkeys: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        dot::export(config, || monkeys.draw())?;
+        let mut jobs = monkeys.jobs.clone();
+        let root = monkeys
+            .root
+            .replace(['+', '-', '*', '/'], "=")
+            .parse()
+            .map_err(|_| {
+                Error::NoSolution("'root' has no operation to turn into an equation".into())
+            })?;
+        jobs.insert("root".into(), root);
+        if jobs.insert("humn".into(), Expr::Free).is_none() {
+            return Err(ParseError::missing("a monkey named 'humn'").into());
+        }
+
+        let forced: HashMap<String, i64> = jobs["root"].solve(true.into(), &jobs);
+        debug!("Forced: {:?}", forced);
+
+        let humn = forced
+            .get("humn")
+            .ok_or_else(|| Error::NoSolution("no number for 'humn' makes 'root' equal".into()))?;
+        Ok((*humn).into())
+    }
+}
@@ -0,0 +1,585 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufReader,
+};
+
+use crate::{
+    debug,
+    error::{Expected, ParseError},
+    frames::Recorder,
+    generate::Rng,
+    inputs::Text,
+    trace, Answer, Config, Day, Error, Problem,
+};
+use lk_math::{
+    line::Line,
+    modular::{Modi32, ModularAddAssign},
+    prelude::*,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    TurnLeft,
+    TurnRight,
+    Walk(i32),
+}
+
+fn rotation_to_direction(rotation: Modi32<4>) -> V2<i32> {
+    match rotation.get() % 4 {
+        0 => V2::from_xy(1, 0),
+        1 => V2::from_xy(0, 1),
+        2 => V2::from_xy(-1, 0),
+        3 => V2::from_xy(0, -1),
+        _ => panic!(),
+    }
+}
+
+fn parse_instructions(s: &str) -> Result<Vec<Instruction>, Expected<'_>> {
+    use Instruction::*;
+    fn walk(steps: &str) -> Result<Instruction, Expected<'_>> {
+        steps
+            .parse()
+            .map(Walk)
+            .map_err(|_| Expected::new(steps, "a step count"))
+    }
+
+    let mut results = vec![];
+    let mut number_start = None;
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_digit() {
+            number_start.get_or_insert(i);
+        } else {
+            if let Some(start) = number_start.take() {
+                results.push(walk(&s[start..i])?);
+            }
+
+            results.push(match c {
+                'L' => TurnLeft,
+                'R' => TurnRight,
+                _ => return Err(Expected::new(&s[i..], "'L', 'R' or a step count")),
+            });
+        }
+    }
+    if let Some(start) = number_start {
+        results.push(walk(&s[start..])?);
+    }
+    Ok(results)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FaceEdge {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl FaceEdge {
+    fn indices(&self) -> (usize, usize) {
+        match self {
+            FaceEdge::Top => (0, 1),
+            FaceEdge::Right => (1, 3),
+            FaceEdge::Bottom => (2, 3),
+            FaceEdge::Left => (0, 2),
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [Self::Top, Self::Right, Self::Bottom, Self::Left].into_iter()
+    }
+}
+
+// NOTE(lubo): Topology of face
+//  0------1------> X-axis (RIGHT)
+//  |      |
+//  |      |
+//  2------3
+//  |
+//  | Y-axis (DOWN)
+//  V
+type Vert = u8;
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+struct Face([Vert; 4]);
+impl Face {
+    fn get_edge_ordered(&self, edge: FaceEdge) -> [Vert; 2] {
+        let (a, b) = edge.indices();
+        [self.0[a], self.0[b]]
+    }
+    fn get_edge(&self, edge: FaceEdge) -> HashSet<Vert> {
+        self.get_edge_ordered(edge).iter().cloned().collect()
+    }
+    fn is_edge(&self, edge: FaceEdge, vertset: &HashSet<Vert>) -> bool {
+        self.get_edge(edge).eq(vertset)
+    }
+    fn find_edge(&self, vertset: &HashSet<Vert>) -> Option<FaceEdge> {
+        FaceEdge::iter().find(|x| self.is_edge(*x, vertset))
+    }
+    // fn has_vert(&self, vert: &Vert) -> bool {
+    //     self.0.iter().any(|x| x == vert)
+    // }
+    // fn get_opposite_vert_mut(&mut self, vert: &Vert) -> Option<&mut Vert> {
+    //     let a = self.iter().position(|x| x == vert);
+    //     match a {
+    //         Some(a) => Some(
+    //             &mut self.0[match a {
+    //                 0 => 3,
+    //                 1 => 2,
+    //                 2 => 1,
+    //                 3 => 0,
+    //                 _ => panic!(),
+    //             }],
+    //         ),
+    //         None => None,
+    //     }
+    // }
+    fn get_opposite_vert(&self, vert: &Vert) -> Option<&Vert> {
+        let a = self.iter().position(|x| x == vert);
+        match a {
+            Some(a) => Some(
+                &self.0[match a {
+                    0 => 3,
+                    1 => 2,
+                    2 => 1,
+                    3 => 0,
+                    _ => panic!(),
+                }],
+            ),
+            None => None,
+        }
+    }
+    fn iter(&self) -> impl Iterator<Item = &Vert> + '_ {
+        self.0.iter()
+    }
+    fn get_set(&self) -> HashSet<Vert> {
+        self.iter().cloned().collect()
+    }
+    fn intersection(&self, other: &Self) -> HashSet<Vert> {
+        self.get_set()
+            .intersection(&other.get_set())
+            .cloned()
+            .collect()
+    }
+    fn shares_edge(&self, other: &Self) -> bool {
+        self.intersection(other).len() == 2
+    }
+}
+
+fn cube_wrap_rule_1(faces: &[Face]) -> HashMap<Vert, Vert> {
+    let mut relabels = HashMap::new();
+
+    let len = faces.len();
+    for i in 0..len {
+        for j in 0..len {
+            if i == j {
+                continue;
+            }
+            for k in 0..j {
+                if faces[i].shares_edge(&faces[j]) && faces[i].shares_edge(&faces[k]) {
+                    let shared = faces[j].intersection(&faces[k]);
+                    // Rule 1
+                    if shared.len() == 1 {
+                        // let shared = shared.drain().next().unwrap();
+
+                        let ij = faces[i].intersection(&faces[j]);
+                        let ik = faces[i].intersection(&faces[k]);
+
+                        let ij_not_k = ij.difference(&shared).next().unwrap();
+                        let ik_not_j = ik.difference(&shared).next().unwrap();
+
+                        let merge1 = *faces[j].get_opposite_vert(ij_not_k).unwrap();
+                        let merge2 = *faces[k].get_opposite_vert(ik_not_j).unwrap();
+
+                        relabels.insert(merge1.max(merge2), merge1.min(merge2));
+
+                        debug!(
+                            "Rule one for faces {}, {}, {}. Joining {}-{} and {}-{}",
+                            i,
+                            j,
+                            k,
+                            shared.iter().next().unwrap(),
+                            merge1,
+                            shared.iter().next().unwrap(),
+                            merge2,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    relabels
+}
+
+fn solve_cube_minimap(
+    minimap: Array2d<char>,
+) -> Vec<(FaceEdge, FaceEdge, i32, LineV2i32, LineV2i32)> {
+    let face_ps = minimap.find_all_items(&'#');
+    let minimap_dims: V2i32 = V2::new(minimap.dims).try_into().unwrap();
+    let vert_grid_size = minimap_dims + V2::from_xy(1, 1);
+
+    let original_faces = face_ps
+        .into_iter()
+        .map(|p| {
+            let a = vert_grid_size.index_unchecked(p).unwrap();
+            let b = vert_grid_size
+                .index_unchecked(p + V2::from_xy(1, 0))
+                .unwrap();
+            let c = vert_grid_size
+                .index_unchecked(p + V2::from_xy(0, 1))
+                .unwrap();
+            let d = vert_grid_size
+                .index_unchecked(p + V2::from_xy(1, 1))
+                .unwrap();
+
+            let a: Vert = a.try_into().unwrap();
+            let b: Vert = b.try_into().unwrap();
+            let c: Vert = c.try_into().unwrap();
+            let d: Vert = d.try_into().unwrap();
+
+            Face([a, b, c, d])
+        })
+        .collect::<Vec<_>>();
+
+    let mut faces = original_faces.clone();
+
+    loop {
+        let relabels = cube_wrap_rule_1(&faces[..]);
+        if relabels.is_empty() {
+            break;
+        }
+
+        faces.iter_mut().for_each(|face| {
+            face.0.iter_mut().for_each(|vert| {
+                if relabels.contains_key(vert) {
+                    *vert = *relabels.get(vert).unwrap();
+                }
+            })
+        });
+    }
+
+    let mut edges_to_glue = vec![];
+    for face in 0..6 {
+        'edge: for edge in FaceEdge::iter() {
+            let e = faces[face].get_edge(edge);
+
+            for other in 0..6 {
+                if face != other {
+                    if let Some(a) = faces[other].find_edge(&e) {
+                        trace!("{}'s {:?} shares edge with {}'s {:?}", face, edge, other, a);
+
+                        let rot = (4 + 2 + a as i32 - edge as i32) % 4;
+                        trace!("Rotation is {} ({} degrees)", rot, rot * 90);
+
+                        let edge_a = original_faces[face].get_edge_ordered(edge);
+                        let edge_b = original_faces[other].get_edge_ordered(a);
+
+                        let a0 = vert_grid_size.unindex(edge_a[0] as usize).unwrap();
+                        let a1 = vert_grid_size.unindex(edge_a[1] as usize).unwrap();
+                        let mut b0 = vert_grid_size.unindex(edge_b[0] as usize).unwrap();
+                        let mut b1 = vert_grid_size.unindex(edge_b[1] as usize).unwrap();
+
+                        if a0 == b0 && a1 == b1 {
+                        } else {
+                            let relabeled_edge_a = faces[face].get_edge_ordered(edge);
+                            let mut relabeled_edge_b = faces[other].get_edge_ordered(a);
+
+                            if relabeled_edge_a[0] == relabeled_edge_b[0] {
+                                // NOTE(lubo): Already in the right order
+                            } else {
+                                assert_eq!(relabeled_edge_a[0], relabeled_edge_b[1]);
+                                relabeled_edge_b.swap(0, 1);
+                                std::mem::swap(&mut b0, &mut b1);
+                            }
+                            assert_eq!(relabeled_edge_a[0], relabeled_edge_b[0]);
+
+                            edges_to_glue.push((
+                                edge,
+                                a,
+                                rot,
+                                Line::new(a0, a1),
+                                Line::new(b0, b1),
+                            ));
+                            debug!("[{}, {}]   <-->   [{}, {}]   (Rot {})", a0, a1, b0, b1, rot);
+                        }
+
+                        continue 'edge;
+                    }
+                }
+            }
+        }
+    }
+
+    edges_to_glue
+}
+
+/// The board, padded with spaces to a rectangle, and the path to follow.
+pub struct Notes {
+    map: Array2d<char>,
+    instructions: Vec<Instruction>,
+}
+
+/// Follows the path, wrapping around the board or, with `B`, around the cube with faces
+/// `face` tiles wide. The trail so far is recorded after every instruction.
+fn follow_path<const B: bool>(notes: &Notes, face: usize, recorder: &mut Recorder) -> i32 {
+    let Notes { map, instructions } = notes;
+
+    let mut minimap = Array2d::with_dimensions(map.width() / face, map.height() / face, '.');
+    for y in 0..minimap.height() {
+        for x in 0..minimap.width() {
+            let p = V2::from_xy(x as i32, y as i32);
+            let q = p * face.try_into().unwrap();
+            let v = *map.get(q).unwrap();
+            if v != ' ' {
+                minimap.set(p, '#');
+            }
+        }
+    }
+    debug!("{}", minimap);
+    let mut teleport_stripes = vec![];
+    let edges_to_glue = solve_cube_minimap(minimap);
+    let mut map = map.padded(1, ' ');
+    for (side1, side2, rot, a, b) in edges_to_glue.into_iter() {
+        let mut a = a.scale(face.try_into().unwrap()).offset(Vector::all(1));
+        let mut b = b.scale(face.try_into().unwrap()).offset(Vector::all(1));
+
+        if side1 == FaceEdge::Left {
+            a = a.offset(V2::from_xy(-1, 0));
+        }
+        if side1 == FaceEdge::Top {
+            a = a.offset(V2::from_xy(0, -1));
+        }
+        if side2 == FaceEdge::Left {
+            b = b.offset(V2::from_xy(-1, 0));
+        }
+        if side2 == FaceEdge::Top {
+            b = b.offset(V2::from_xy(0, -1));
+        }
+
+        assert!(a.start.x() <= a.end.x() && a.start.y() <= a.end.y());
+
+        if b.start.x() <= b.end.x() && b.start.y() <= b.end.y() {
+            // ok
+        } else {
+            debug!("B is inverted :(");
+            b = b.offset(b.delta().elementwise_unary(|x| x.signum()));
+        }
+
+        map.draw_line::<false>(a, 'O');
+        map.draw_line::<false>(b, 'O');
+        trace!("{}", map);
+
+        teleport_stripes.push((rot, a, b));
+    }
+    debug!("{}", map);
+
+    let map_dims = V2::from_xy(
+        map.dims[0].try_into().unwrap(),
+        map.dims[1].try_into().unwrap(),
+    );
+    let mut draw_map = map.clone();
+    let mut pos = map.find_item(&'.').unwrap();
+    let mut rotation = Modi32::new(0);
+    for &instruction in instructions {
+        trace!("{:?}", instruction);
+        match instruction {
+            Instruction::TurnLeft => rotation += Modi32::new(-1),
+            Instruction::TurnRight => rotation += Modi32::new(1),
+            Instruction::Walk(w) => {
+                let mut probe: V2i32 = pos;
+                let mut probe_rot = rotation;
+                'steps: for _step in 0..w {
+                    'this_step: loop {
+                        probe.addassign_n(rotation_to_direction(probe_rot), map_dims);
+                        match map.get(probe).unwrap() {
+                            '.' => {
+                                pos = probe;
+                                rotation = probe_rot;
+                                draw_map.set(pos, ['>', 'v', '<', '^'][rotation.get() as usize]);
+                                break 'this_step;
+                            }
+                            'O' => {
+                                if B {
+                                    for (rot, from, to) in teleport_stripes.iter() {
+                                        if let Some(from_index) =
+                                            from.iter::<false>().position(|x| x == probe)
+                                        {
+                                            probe = to.iter::<false>().nth(from_index).unwrap();
+                                            probe_rot += Modi32::new(*rot);
+                                            probe.addassign_n(
+                                                rotation_to_direction(probe_rot),
+                                                map_dims,
+                                            );
+
+                                            match map.get(probe).unwrap() {
+                                                '.' => (),
+                                                '#' => break 'steps,
+                                                _ => panic!(),
+                                            }
+
+                                            pos = probe;
+                                            rotation = probe_rot;
+                                            draw_map.set(
+                                                pos,
+                                                ['>', 'v', '<', '^'][rotation.get() as usize],
+                                            );
+                                            break 'this_step;
+                                        }
+                                    }
+                                    panic!("Unhandled teleport tile!");
+                                }
+                            }
+                            '#' => break 'steps,
+                            ' ' => (),
+                            _ => (),
+                        }
+                    }
+                }
+            }
+        }
+        trace!("{}", draw_map);
+        trace!("Pos {} Rot {}", pos, rotation);
+        recorder.step(|| draw_map.to_string());
+    }
+
+    debug!("{}", draw_map);
+
+    debug!("Pos {} Rot {}", pos, rotation);
+    let row = pos.y();
+    let col = pos.x();
+    let rot = match rotation.get() {
+        0 => 0,
+        1 => 1,
+        2 => 2,
+        3 => 3,
+        _ => panic!(),
+    };
+    let result = 1000 * row + 4 * col + rot;
+    debug!("Row {} Col {} Rot {} Final {}", row, col, rot, result);
+
+    result
+}
+
+// The example's cube faces are 4 tiles wide.
+fn face_size(config: &Config) -> Result<usize, Error> {
+    config.get("face", 50)
+}
+
+impl Problem for Day<22> {
+    type Input = Notes;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        let text = Text::read(reader)?;
+        let blocks = text.blocks();
+        let (map, path) = match &blocks[..] {
+            [map, path] => (&map.lines, path),
+            _ => {
+                let expected = "a map and a path separated by a blank line";
+                return Err(ParseError::missing(expected).into());
+            }
+        };
+
+        let mut instructions = vec![];
+        for (offset, &line) in path.lines.iter().enumerate() {
+            let parsed =
+                parse_instructions(line).map_err(|e| e.on_line(path.line + offset, line))?;
+            instructions.extend(parsed);
+        }
+
+        let map_width = map.iter().map(|x| x.len()).max().unwrap();
+        let map = map.iter().map(|x| {
+            let a = format!("{:1$}", x, map_width);
+            a
+        });
+        assert!(map.clone().all(|x| x.len() == map_width));
+        let map = Array2d {
+            data: map.clone().collect::<Vec<_>>().concat().chars().collect(),
+            dims: [map_width, map.clone().count()],
+            dim_strides: [1, map_width],
+        };
+
+        Ok(Notes { map, instructions })
+    }
+
+    fn part1(notes: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut recorder = Recorder::from_config(config)?;
+        let password = follow_path::<false>(notes, face_size(config)?, &mut recorder);
+        recorder.finish()?;
+        Ok(password.into())
+    }
+
+    fn part2(notes: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut recorder = Recorder::from_config(config)?;
+        let password = follow_path::<true>(notes, face_size(config)?, &mut recorder);
+        recorder.finish()?;
+        Ok(password.into())
+    }
+}
+
+/// A random unfolded cube with faces `size` tiles wide, about one tile in eight a wall, and a
+/// path of `10 * size` moves. Solve it with `-s face=<size>`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // The eleven ways to unfold a cube. Rotated or mirrored they still fold up.
+    const NETS: [&[&str]; 11] = [
+        &["#   ", "####", "#   "],
+        &["#   ", "####", " #  "],
+        &["#   ", "####", "  # "],
+        &["#   ", "####", "   #"],
+        &[" #  ", "####", " #  "],
+        &[" #  ", "####", "  # "],
+        &["##  ", " ###", " #  "],
+        &["##  ", " ###", "  # "],
+        &["##  ", " ###", "   #"],
+        &["##  ", " ## ", "  ##"],
+        &["###  ", "  ###"],
+    ];
+    let face = size.max(1);
+
+    let mut faces = rng
+        .pick(&NETS)
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.match_indices('#').map(move |(x, _)| (x, y)))
+        .collect::<Vec<_>>();
+    if rng.one_in(2) {
+        faces.iter_mut().for_each(|(x, y)| std::mem::swap(x, y));
+    }
+    let width = faces.iter().map(|&(x, _)| x + 1).max().unwrap();
+    let height = faces.iter().map(|&(_, y)| y + 1).max().unwrap();
+    if rng.one_in(2) {
+        faces.iter_mut().for_each(|(x, _)| *x = width - 1 - *x);
+    }
+    if rng.one_in(2) {
+        faces.iter_mut().for_each(|(_, y)| *y = height - 1 - *y);
+    }
+
+    let mut map = vec![vec![' '; width * face]; height * face];
+    for &(fx, fy) in faces.iter() {
+        for row in map[fy * face..][..face].iter_mut() {
+            for tile in row[fx * face..][..face].iter_mut() {
+                *tile = if rng.one_in(8) { '#' } else { '.' };
+            }
+        }
+    }
+    // The path starts on the leftmost open tile of the top row.
+    if let Some(tile) = map[0].iter_mut().find(|tile| **tile != ' ') {
+        *tile = '.';
+    }
+
+    let mut text = String::new();
+    for row in map {
+        text.push_str(row.into_iter().collect::<String>().trim_end());
+        text.push('\n');
+    }
+    text.push('\n');
+    for step in 0..10 * face {
+        if step > 0 {
+            text.push(*rng.pick(&['L', 'R']));
+        }
+        text.push_str(&rng.range(1..=2 * face as i64).to_string());
+    }
+    text.push('\n');
+    text
+}
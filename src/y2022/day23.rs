@@ -1,25 +1,27 @@
 #![allow(clippy::reversed_empty_ranges)]
 
 use ndarray::{prelude::*, Zip};
-use std::io::{BufReader, Read};
+use std::io::BufReader;
+
+use crate::{error::ParseError, inputs::Text};
 
 type Board = Array2<i8>;
 
-fn parse(n: usize, x: &[u8]) -> Board {
-    let pad_size: usize = n;
-    let m: usize = n + 2 * pad_size;
-    let a = Array::from_iter(x.iter().filter_map(|&b| match b {
-        b'#' => Some(1),
-        b'.' => Some(0),
-        _ => None,
-    }));
-    let a = a.into_shape((n, n)).unwrap();
+fn parse_board(text: &Text) -> Result<Board, ParseError> {
+    let tiles = text.grid()?;
+    for (index, line) in text.lines().enumerate() {
+        if let Some(i) = line.find(|c| c != '#' && c != '.') {
+            return Err(ParseError::at(index + 1, line, &line[i..], "'#' or '.'"));
+        }
+    }
 
+    let (height, width) = tiles.dim();
+    let pad_size = height.max(width);
+    let m = 3 * pad_size;
     let mut map = Board::from_elem((m, m), 0);
-    let pad_size: i32 = pad_size.try_into().unwrap();
-    map.slice_mut(s![pad_size..-pad_size, pad_size..-pad_size])
-        .assign(&a);
-    map
+    map.slice_mut(s![pad_size..pad_size + height, pad_size..pad_size + width])
+        .assign(&tiles.map(|&c| (c == '#') as i8));
+    Ok(map)
 }
 
 const PX: i8 = 1; // East
@@ -197,27 +199,73 @@ fn iterate(z: &mut Board, dir_order: &[i8]) -> bool {
     false
 }
 
-use crate::{Day, Problem};
+use crate::{
+    debug,
+    frames::{grid, Recorder},
+    image::{self, Image, Palette},
+    simulation::Simulation,
+    trace, Answer, Config, Day, Error, Problem,
+};
 use lk_math::{aabb::Aabb, vector::Vector};
 
-impl Problem for Day<2301> {
-    fn solve_buffer<T, W>(mut reader: BufReader<T>, writer: &mut W)
+fn draw(board: &Board) -> String {
+    grid(board, |&x| if x > 0 { '#' } else { '.' })
+}
+
+/// The elves round by round, with the order they consider directions in next.
+#[derive(Clone)]
+struct Elves {
+    board: Board,
+    dir_order: [i8; 4],
+    /// Whether any elf moved in the last round.
+    moved: bool,
+}
+
+impl Elves {
+    fn new(board: &Board) -> Self {
+        Self {
+            board: board.clone(),
+            dir_order: [NY, PY, NX, PX],
+            moved: true,
+        }
+    }
+}
+
+impl Simulation for Elves {
+    /// The whole state, so a cycle is only found when the elves really are back where they were.
+    type Fingerprint = (Board, [i8; 4], bool);
+
+    fn step(&mut self) {
+        self.moved = !iterate(&mut self.board, &self.dir_order);
+        self.dir_order.rotate_left(1);
+    }
+
+    fn fingerprint(&self) -> Option<Self::Fingerprint> {
+        Some((self.board.clone(), self.dir_order, self.moved))
+    }
+}
+
+impl Problem for Day<23> {
+    /// Elf positions, padded on every side by the longer side of the grid.
+    type Input = Board;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        println!("North: {NY} (NY)  South: {PY} (PY)  West: {NX} (NX)  East: {PX} (PX)");
-
-        let mut buffer = vec![];
-        let n_squared = reader.read_to_end(&mut buffer).unwrap();
-        let n = f64::sqrt(n_squared as f64) as usize;
-        let mut a = parse(n, &buffer);
-        let mut dir_order = vec![NY, PY, NX, PX];
-        let steps = 10;
-        for _ in 0..steps {
-            iterate(&mut a, &dir_order);
-            dir_order.rotate_left(1);
-        }
+        Ok(parse_board(&Text::read(reader)?)?)
+    }
+
+    fn part1(board: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        trace!("North: {NY} (NY)  South: {PY} (PY)  West: {NX} (NX)  East: {PX} (PX)");
+
+        let mut recorder = Recorder::from_config(config)?;
+        let mut elves = Elves::new(board);
+        elves.run_n_with(10, |elves| recorder.step(|| draw(&elves.board)));
+        let a = elves.board;
+        recorder.capture(|| draw(&a));
+        recorder.finish()?;
+        image::export(config, || Image::from_text(&draw(&a), &Palette::default()))?;
 
         let elves = a
             .indexed_iter()
@@ -230,42 +278,36 @@ impl Problem for Day<2301> {
             })
             .collect::<Vec<_>>();
 
-        let elves_aabb = Aabb::covering(&elves).unwrap();
+        let elves_aabb =
+            Aabb::covering(&elves).ok_or_else(|| Error::NoSolution("no elves".into()))?;
         let aabb_dim = elves_aabb.dim();
         let aabb_area = (aabb_dim.x() + 1) * (aabb_dim.y() + 1);
         let elves_count = a.iter().filter(|&&x| x > 0).count();
         let free_spaces = aabb_area - elves_count;
 
-        println!("There are {free_spaces} free spaces in AABB");
-        writeln!(writer, "{free_spaces}").unwrap();
+        debug!("There are {free_spaces} free spaces in AABB");
+        Answer::try_from(free_spaces)
     }
-}
 
-impl Problem for Day<2302> {
-    fn solve_buffer<T, W>(mut reader: BufReader<T>, writer: &mut W)
-    where
-        T: std::io::Read,
-        W: std::io::Write,
-    {
-        let mut buffer = vec![];
-        let n_squared = reader.read_to_end(&mut buffer).unwrap();
-        let n = f64::sqrt(n_squared as f64) as usize;
-        let mut a = parse(n, &buffer);
-        let mut step: usize = 0;
-
-        let mut dir_order = vec![NY, PY, NX, PX];
-        loop {
-            if iterate(&mut a, &dir_order) {
-                break;
-            }
-            dir_order.rotate_left(1);
-            step += 1;
-        }
+    fn part2(board: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut recorder = Recorder::from_config(config)?;
+        let mut elves = Elves::new(board);
+        // The round in which no elf moves counts too.
+        let step = elves
+            .run_until(|elves| {
+                if elves.moved {
+                    recorder.step(|| draw(&elves.board));
+                }
+                !elves.moved
+            })
+            .ok_or_else(|| Error::NoSolution("the elves never stop moving".into()))?;
 
-        // NOTE(lubo): For some reason aoc wants one more than we get here.
-        let step = step + 1;
+        recorder.finish()?;
+        image::export(config, || {
+            Image::from_text(&draw(&elves.board), &Palette::default())
+        })?;
 
-        println!("Simulation terminates in {step} steps.");
-        writeln!(writer, "{step}").unwrap();
+        debug!("Simulation terminates in {step} steps.");
+        Answer::try_from(step)
     }
 }
@@ -0,0 +1,226 @@
+use std::io::BufReader;
+
+use crate::{
+    debug,
+    error::ParseError,
+    frames::Recorder,
+    generate::Rng,
+    image::{self, Image, Palette},
+    info,
+    search::CountedExploration,
+    simulation::Simulation,
+    trace, Answer, Config, Day, Error, Problem,
+};
+use lk_math::{
+    explore::{Exploration, ExploreSignals},
+    prelude::*,
+    sketch::QueueBag,
+};
+
+/// The blizzards minute by minute, each moving a tile a minute and coming back in on the other
+/// side of the valley when it hits a wall.
+#[derive(Clone, PartialEq, Eq)]
+struct Blizzards {
+    left: Vec<V2i32>,
+    up: Vec<V2i32>,
+    right: Vec<V2i32>,
+    down: Vec<V2i32>,
+    /// Size of the valley inside the walls.
+    width: i32,
+    height: i32,
+}
+
+impl Blizzards {
+    /// The valley with a wall on every tile a blizzard is on.
+    fn draw(&self, valley: &Array2d<char>) -> Array2d<char> {
+        let mut map = valley.clone();
+        for p in [&self.left, &self.up, &self.right, &self.down]
+            .into_iter()
+            .flatten()
+        {
+            map.set(*p, '#');
+        }
+        map
+    }
+}
+
+impl Simulation for Blizzards {
+    type Fingerprint = ();
+
+    fn step(&mut self) {
+        let (width, height) = (self.width, self.height);
+        // Inside the walls coordinates go from 1, moving `d` wraps round to there.
+        let wrap = |x: i32, d: i32, size: i32| (x - 1 + d).rem_euclid(size) + 1;
+        for p in self.left.iter_mut() {
+            *p = Vector::from_xy(wrap(p.x(), -1, width), p.y());
+        }
+        for p in self.right.iter_mut() {
+            *p = Vector::from_xy(wrap(p.x(), 1, width), p.y());
+        }
+        for p in self.up.iter_mut() {
+            *p = Vector::from_xy(p.x(), wrap(p.y(), -1, height));
+        }
+        for p in self.down.iter_mut() {
+            *p = Vector::from_xy(p.x(), wrap(p.y(), 1, height));
+        }
+    }
+}
+
+/// Valley walls plus the blizzards, with the valley precomputed for every minute of one cycle.
+#[derive(Clone)]
+pub struct BlizzardMap {
+    map: Array2d<char>,
+    cache: Vec<Array2d<char>>,
+}
+
+impl BlizzardMap {
+    pub fn from_map(mut map: Array2d<char>) -> Self {
+        let mut blizzards = Blizzards {
+            right: map.find_all_items(&'>'),
+            up: map.find_all_items(&'^'),
+            left: map.find_all_items(&'<'),
+            down: map.find_all_items(&'v'),
+            width: map.width() as i32 - 2,
+            height: map.height() as i32 - 2,
+        };
+
+        map.replace_all(&'>', &'.');
+        map.replace_all(&'^', &'.');
+        map.replace_all(&'<', &'.');
+        map.replace_all(&'v', &'.');
+
+        // Every blizzard is back where it started after `width * height` minutes at
+        // the latest, often sooner.
+        let start = blizzards.clone();
+        let mut cache = vec![];
+        blizzards.run_until(|blizzards| {
+            if !cache.is_empty() && blizzards == &start {
+                return true;
+            }
+            cache.push(blizzards.draw(&map));
+            false
+        });
+
+        Self { map, cache }
+    }
+
+    pub fn at_time(&self, t: i32) -> &Array2d<char> {
+        &self.cache[t as usize % self.cycle_length()]
+    }
+
+    /// Minutes until the blizzards are back where they started.
+    pub fn cycle_length(&self) -> usize {
+        self.cache.len()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Point {
+    p: V2i32,
+    t: i32,
+}
+
+impl IterateNeighbours<Array2d<char>> for Point {
+    fn neighbours(&self, context: &Array2d<char>) -> Vec<Self> {
+        self.p
+            .neighbours(context)
+            .into_iter()
+            .map(|x| Self {
+                p: x,
+                t: self.t + 1,
+            })
+            .collect()
+    }
+}
+
+impl Problem for Day<24> {
+    /// The valley and the tile the expedition starts on.
+    type Input = (BlizzardMap, V2i32);
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        let map = Array2d::from_buffer(reader)
+            .map_err(|_| ParseError::missing("a rectangular valley map"))?;
+
+        debug!("Map size: {}x{}", map.width(), map.height());
+
+        let start = map
+            .find_item(&'.')
+            .ok_or_else(|| ParseError::missing("an open tile '.' to start from"))?;
+        // let end = map.find_last(&'.').unwrap();
+        Ok((BlizzardMap::from_map(map), start))
+    }
+
+    fn part1((valley, start): &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let (blizz, start) = (valley.clone(), *start);
+
+        let mut result = None;
+        let mut exp = Exploration::new(blizz.map.clone(), blizz);
+        let stats = exp.explore_counted::<_, _, QueueBag<_>>(
+            Point { p: start, t: 0 },
+            |p, map, _blizz| {
+                if p.p.x() > 3 || p.p.y() > 3 {
+                    trace!("{:?}", p);
+                }
+
+                if p.p.y() + 1 == map.height().try_into().unwrap() {
+                    result = Some(p.t);
+                    ExploreSignals::ReachedGoal
+                } else {
+                    ExploreSignals::Explore
+                }
+            },
+            |_p, n, _map, blizz| {
+                let map_at_time = blizz.at_time(n.t);
+                let n_standing_on = map_at_time.get(n.p).unwrap();
+                n_standing_on == &'.'
+            },
+        );
+        info!("{}", stats);
+
+        debug!("Found in {result:?} steps.");
+        let result =
+            result.ok_or_else(|| Error::NoSolution("no path through the blizzards".into()))?;
+
+        // The search has no single state to show, so this records how the valley
+        // looks every minute of the way.
+        let mut recorder = Recorder::from_config(config)?;
+        for t in 0..=result {
+            recorder.step(|| valley.at_time(t).to_string());
+        }
+        recorder.finish()?;
+        image::export(config, || {
+            Image::from_text(&valley.at_time(result).to_string(), &Palette::default())
+        })?;
+
+        Ok(result.into())
+    }
+}
+
+/// A valley `size` tiles wide and a fifth as high inside its walls, about half of it blizzards.
+/// Like in the real input, no blizzard moves up or down in the entrance or exit columns, where
+/// it would leave the valley.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(2);
+    let height = (width / 5).max(2);
+
+    let mut text = format!("#.{}\n", "#".repeat(width));
+    for _ in 0..height {
+        text.push('#');
+        for x in 0..width {
+            let directions: &[char] = match x == 0 || x + 1 == width {
+                true => &['<', '>'],
+                false => &['<', '>', '^', 'v'],
+            };
+            text.push(match rng.one_in(2) {
+                true => *rng.pick(directions),
+                false => '.',
+            });
+        }
+        text.push_str("#\n");
+    }
+    text.push_str(&format!("{}.#\n", "#".repeat(width)));
+    text
+}
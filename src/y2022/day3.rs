@@ -1,6 +1,6 @@
 use std::{collections::HashSet, io::prelude::*, io::BufReader};
 
-use crate::Problem;
+use crate::{error::ParseError, Answer, Config, Day, Error, Problem};
 
 fn item_priority(item: char) -> usize {
     match item {
@@ -10,15 +10,36 @@ fn item_priority(item: char) -> usize {
     }
 }
 
-pub struct Day3CommonItemInCompartments;
-impl Problem for Day3CommonItemInCompartments {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+fn check_items(line_number: usize, line: &str) -> Result<(), ParseError> {
+    match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        Some((i, _)) => Err(ParseError::at(
+            line_number,
+            line,
+            &line[i..],
+            "an item letter",
+        )),
+        None => Ok(()),
+    }
+}
+
+impl Problem for Day<3> {
+    /// One rucksack per line.
+    type Input = Vec<String>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+        for (index, line) in lines.iter().enumerate() {
+            check_items(index + 1, line)?;
+        }
+        Ok(lines)
+    }
+
+    fn part1(rucksacks: &Self::Input, _config: &Config) -> Result<Answer, Error> {
         let mut priority_sum = 0;
-        for line in reader.lines().map(|x| x.unwrap()) {
+        for line in rucksacks {
             let left = &line[0..line.len() / 2];
             let right = &line[line.len() / 2..];
 
@@ -38,23 +59,14 @@ impl Problem for Day3CommonItemInCompartments {
             }
         }
 
-        writeln!(writer, "Total duplicate priority sum: {}", priority_sum).unwrap();
+        Answer::try_from(priority_sum)
     }
-}
 
-pub struct Day3CommonItemInGroups;
-impl Problem for Day3CommonItemInGroups {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
-    where
-        T: std::io::Read,
-        W: std::io::Write,
-    {
+    fn part2(rucksacks: &Self::Input, _config: &Config) -> Result<Answer, Error> {
         let mut badge_sum = 0;
 
-        let mut lines = reader.lines();
-        while let (Some(Ok(line1)), Some(Ok(line2)), Some(Ok(line3))) =
-            (lines.next(), lines.next(), lines.next())
-        {
+        for group in rucksacks.chunks_exact(3) {
+            let (line1, line2, line3) = (&group[0], &group[1], &group[2]);
             let mut set = HashSet::new();
             let mut set2 = HashSet::new();
 
@@ -76,6 +88,6 @@ impl Problem for Day3CommonItemInGroups {
             }
         }
 
-        writeln!(writer, "Badge sum: {}", badge_sum).unwrap();
+        Answer::try_from(badge_sum)
     }
 }
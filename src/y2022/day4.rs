@@ -0,0 +1,126 @@
+use std::{io::prelude::*, io::BufReader};
+
+use lk_math::interval::{Interval, IntervalExt};
+
+use crate::{
+    error::{parse_field, ParseError},
+    generate::Rng,
+    Answer, Config, Day, Error, Problem,
+};
+
+pub trait IntervalRelation {
+    fn test(a0: i32, a1: i32, b0: i32, b1: i32) -> bool;
+    /// The same relation with plain comparisons, see `Day4Plain`.
+    fn test_plain(a0: i32, a1: i32, b0: i32, b1: i32) -> bool;
+}
+
+pub struct OneFullyInsideAnother;
+pub struct Overlap;
+
+impl IntervalRelation for OneFullyInsideAnother {
+    fn test(a0: i32, a1: i32, b0: i32, b1: i32) -> bool {
+        let a = a0..a1 + 1;
+        let b = b0..b1 + 1;
+        a.dominates_or_is_dominated_by(&b)
+    }
+    fn test_plain(a0: i32, a1: i32, b0: i32, b1: i32) -> bool {
+        (a0 >= b0 && a1 <= b1) || (b0 >= a0 && b1 <= a1)
+    }
+}
+impl IntervalRelation for Overlap {
+    fn test(a0: i32, a1: i32, b0: i32, b1: i32) -> bool {
+        let a = a0..a1 + 1;
+        let b = b0..b1 + 1;
+        a.overlaps(&b)
+    }
+    fn test_plain(a0: i32, a1: i32, b0: i32, b1: i32) -> bool {
+        a1 >= b0 && a0 <= b1
+    }
+}
+
+/// Two elves' inclusive section ranges.
+type Pair = ((i32, i32), (i32, i32));
+
+/// Counts the pairs of section ranges for which `test` holds.
+fn count_pairs(pairs: &[Pair], test: fn(i32, i32, i32, i32) -> bool) -> usize {
+    pairs
+        .iter()
+        .filter(|&&((a0, a1), (b0, b1))| test(a0, a1, b0, b1))
+        .count()
+}
+
+impl Problem for Day<4> {
+    type Input = Vec<Pair>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut pairs = vec![];
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line_number = index + 1;
+            let range = |text: &str| -> Result<(i32, i32), ParseError> {
+                let (lo, hi) = text.split_once('-').ok_or_else(|| {
+                    ParseError::at(line_number, &line, text, "a range like '2-4'")
+                })?;
+                Ok((
+                    parse_field(line_number, &line, lo, "a section number")?,
+                    parse_field(line_number, &line, hi, "a section number")?,
+                ))
+            };
+
+            let (a, b) = line.split_once(',').ok_or_else(|| {
+                ParseError::at(line_number, &line, &line, "two ranges like '2-4,6-8'")
+            })?;
+            pairs.push((range(a)?, range(b)?));
+        }
+
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(count_pairs(pairs, OneFullyInsideAnother::test))
+    }
+
+    fn part2(pairs: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(count_pairs(pairs, Overlap::test))
+    }
+}
+
+/// Day 4 without lk_math's intervals, to check them against.
+pub struct Day4Plain;
+
+impl Problem for Day4Plain {
+    type Input = Vec<Pair>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
+    where
+        T: std::io::Read,
+    {
+        Day::<4>::parse(reader)
+    }
+
+    fn part1(pairs: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(count_pairs(pairs, OneFullyInsideAnother::test_plain))
+    }
+
+    fn part2(pairs: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(count_pairs(pairs, Overlap::test_plain))
+    }
+}
+
+/// `size` pairs of section ranges between 1 and 99, like the real ones.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let lo = rng.range(1..=99);
+        (lo, rng.range(lo..=99))
+    };
+    let mut text = String::new();
+    for _ in 0..size.max(1) {
+        let ((a0, a1), (b0, b1)) = (range(rng), range(rng));
+        text.push_str(&format!("{}-{},{}-{}\n", a0, a1, b0, b1));
+    }
+    text
+}
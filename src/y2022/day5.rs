@@ -1,8 +1,8 @@
-use std::{collections::HashMap, io::prelude::*, io::BufReader, marker::PhantomData};
+use std::{collections::HashMap, io::prelude::*, io::BufReader};
 
-use crate::Problem;
+use crate::{error::ParseError, pattern::Pattern, Answer, Config, Day, Error, Problem};
 
-trait CrateMover {
+pub trait CrateMover {
     fn move_crates(stacks: &mut HashMap<usize, Vec<char>>, count: usize, from: usize, to: usize);
 }
 
@@ -36,28 +36,60 @@ impl CrateMover for CrateMover9001 {
     }
 }
 
+const MOVE: Pattern = Pattern::new("move {} from {} to {}");
+
+fn parse_move(line_number: usize, line: &str) -> Result<(usize, usize, usize), ParseError> {
+    let fields = MOVE.capture(line_number, line)?;
+    Ok((
+        fields.parse(0, "a crate count")?,
+        fields.parse(1, "a stack number")?,
+        fields.parse(2, "a stack number")?,
+    ))
+}
+
 enum Stages {
     ReadingInitialState,
     MovingCrates,
 }
 
-pub struct Day5<T> {
-    phantom: PhantomData<T>,
+/// The starting stacks, indexed from 1 like in the puzzle, and the moves as `(count, from, to)`.
+pub struct Procedure {
+    stacks: HashMap<usize, Vec<char>>,
+    moves: Vec<(usize, usize, usize)>,
+}
+
+/// Runs the procedure with crane `T` and reads the crates left on top.
+fn rearrange<T: CrateMover>(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+    for &(count, from, to) in &procedure.moves {
+        T::move_crates(&mut stacks, count, from, to);
+    }
+
+    let mut keys = stacks.keys().cloned().collect::<Vec<usize>>();
+    keys.sort();
+
+    let top_elements: String = keys
+        .iter()
+        .map(|key| stacks.get(key).unwrap().last().unwrap())
+        .collect();
+
+    //let top_elements: String = stacks.values().map(|stack| stack.last().unwrap()).collect();
+    top_elements
 }
 
-impl<T> Problem for Day5<T>
-where
-    T: CrateMover,
-{
-    fn solve_buffer<U, W>(reader: BufReader<U>, writer: &mut W)
+impl Problem for Day<5> {
+    type Input = Procedure;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
-        U: std::io::Read,
-        W: std::io::Write,
+        T: std::io::Read,
     {
         let mut stage = Stages::ReadingInitialState;
 
         let mut stacks: HashMap<usize, Vec<char>> = HashMap::new();
-        for line in reader.lines().map(|x| x.unwrap()) {
+        let mut moves = vec![];
+        for (line_index, line) in reader.lines().enumerate() {
+            let line = line?;
             stage = match stage {
                 Stages::ReadingInitialState => {
                     if line.contains('[') {
@@ -82,39 +114,22 @@ where
                     }
                 }
                 Stages::MovingCrates => {
-                    if let [_, a] = line.split("move").collect::<Vec<&str>>()[..] {
-                        if let [count, a] = a.split("from").collect::<Vec<&str>>()[..] {
-                            if let [from, to] = a.split("to").collect::<Vec<&str>>()[..] {
-                                if let Ok(count) = count.trim().parse::<usize>() {
-                                    if let Ok(from) = from.trim().parse::<usize>() {
-                                        if let Ok(to) = to.trim().parse::<usize>() {
-                                            T::move_crates(&mut stacks, count, from, to);
-                                        }
-                                    }
-                                }
-                            }
-                        }
+                    if !line.trim().is_empty() {
+                        moves.push(parse_move(line_index + 1, &line)?);
                     }
                     Stages::MovingCrates
                 }
             };
         }
 
-        let mut keys = stacks.keys().cloned().collect::<Vec<usize>>();
-        keys.sort();
-
-        let top_elements: String = keys
-            .iter()
-            .map(|key| stacks.get(key).unwrap().last().unwrap())
-            .collect();
-
-        //let top_elements: String = stacks.values().map(|stack| stack.last().unwrap()).collect();
-        writeln!(
-            writer,
-            "Using {}, Top items from each stack: {}",
-            std::any::type_name::<T>(),
-            top_elements
-        )
-        .unwrap();
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Ok(rearrange::<CrateMover9000>(procedure).into())
+    }
+
+    fn part2(procedure: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Ok(rearrange::<CrateMover9001>(procedure).into())
     }
 }
@@ -1,8 +1,6 @@
 use std::{io::prelude::*, io::BufReader};
 
-use crate::Problem;
-
-pub struct Day6<const T: usize>;
+use crate::{error::ParseError, Answer, Config, Day, Error, Problem};
 
 // NOTE(lubo): This was found to be performant enough... :(
 fn explicit_compare<const C: usize>(chars: Vec<char>) -> Option<usize> {
@@ -47,19 +45,39 @@ fn skip_as_far_as_possible_upon_finding_a_duplicate<const C: usize>(
     None
 }
 
-impl<const C: usize> Problem for Day6<C> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+/// Position right after the first `C` distinct characters in a row.
+fn find_marker<const C: usize>(datastream: &str) -> Result<Answer, Error> {
+    let chars = datastream.chars().collect::<Vec<char>>();
+    let signal_starts_at = explicit_compare::<C>(chars).ok_or_else(|| {
+        ParseError::at(
+            1,
+            datastream,
+            &datastream[datastream.len()..],
+            format!("{} distinct characters in a row", C),
+        )
+    })?;
+
+    Answer::try_from(signal_starts_at)
+}
+
+impl Problem for Day<6> {
+    type Input = String;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        let mut signal_starts_at = 0;
-        let mut lines = reader.lines();
-        if let Some(Ok(first)) = lines.next() {
-            let chars = first.chars().collect::<Vec<char>>();
-            signal_starts_at = explicit_compare::<C>(chars).unwrap();
+        match reader.lines().next() {
+            Some(line) => Ok(line?),
+            None => Err(ParseError::missing("a datastream").into()),
         }
+    }
+
+    fn part1(datastream: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        find_marker::<4>(datastream)
+    }
 
-        writeln!(writer, "Signal starts at: {}", signal_starts_at).unwrap();
+    fn part2(datastream: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        find_marker::<14>(datastream)
     }
 }
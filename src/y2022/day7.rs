@@ -1,8 +1,12 @@
 use std::{collections::HashMap, fmt::Display, io::prelude::*, io::BufReader};
 
-use crate::{Day, Problem};
+use crate::{
+    dot::{self, Graph},
+    error::{parse_field, ParseError},
+    trace, Answer, Config, Day, Error, Problem,
+};
 
-struct FileTree {
+pub struct FileTree {
     immediate_directory_subdirectories: HashMap<String, Vec<String>>,
     immediate_directory_files: HashMap<String, Vec<String>>,
     file_sizes: HashMap<String, usize>,
@@ -80,7 +84,7 @@ struct CachedFileTree {
 }
 
 impl FileTree {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             immediate_directory_subdirectories: HashMap::new(),
             immediate_directory_files: HashMap::new(),
@@ -123,7 +127,108 @@ impl FileTree {
         })
     }
 
-    fn get_directory_size(&self, directory: &str) -> Option<usize> {
+    /// Rebuilds the tree from a terminal session of `cd` and `ls` commands.
+    pub fn from_buffer<T>(reader: BufReader<T>) -> Result<Self, Error>
+    where
+        T: std::io::Read,
+    {
+        let mut tree = FileTree::new();
+        let mut current_path = "".to_string();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line_number = index + 1;
+            let expected = |at: &str, what: &str| ParseError::at(line_number, &line, at, what);
+
+            let args: Vec<&str> = line.split_whitespace().collect();
+
+            match args[..] {
+                ["$", "cd", "/"] => {
+                    current_path = "".to_string();
+                }
+                ["$", "cd", ".."] => {
+                    let a = current_path
+                        .rfind('/')
+                        .ok_or_else(|| expected(args[2], "a directory below '/'"))?;
+                    current_path = current_path[..a].to_string();
+                }
+                ["$", "cd", dirname] => {
+                    let new_path = format!("{}/{}", current_path, dirname);
+                    if !tree
+                        .immediate_directory_subdirectories
+                        .get(&current_path)
+                        .is_some_and(|subdirs| subdirs.contains(&new_path))
+                    {
+                        return Err(expected(dirname, "a directory listed by 'ls'").into());
+                    }
+                    current_path = new_path;
+                }
+                ["$", "ls"] => (),
+                ["$", command, ..] => return Err(expected(command, "'cd' or 'ls'").into()),
+                ["dir", dirname] => {
+                    let dirpath = format!("{}/{}", current_path, dirname);
+                    tree.immediate_directory_subdirectories
+                        .entry(current_path.clone())
+                        .or_default()
+                        .push(dirpath.clone());
+                    tree.immediate_directory_subdirectories
+                        .entry(dirpath.clone())
+                        .or_default();
+                }
+                [size, filename] => {
+                    let filesize: usize = parse_field(line_number, &line, size, "a file size")?;
+                    let filepath = format!("{}/{}", current_path, filename);
+
+                    tree.immediate_directory_files
+                        .entry(current_path.clone())
+                        .or_default()
+                        .push(filepath.clone());
+
+                    tree.file_sizes.insert(filepath, filesize);
+                }
+                _ => return Err(expected(&line, "a command or a directory entry").into()),
+            }
+        }
+
+        Ok(tree)
+    }
+
+    /// Directories with their total size and files with theirs, each linked to what contains it.
+    pub fn draw(&self) -> Graph {
+        let id = |path: &str| match path {
+            "" => "/".to_string(),
+            _ => path.to_string(),
+        };
+        let name = |path: &str| path[path.rfind('/').map_or(0, |i| i + 1)..].to_string();
+
+        let mut dirs = self
+            .immediate_directory_subdirectories
+            .keys()
+            .collect::<Vec<_>>();
+        dirs.sort();
+
+        let mut graph = Graph::directed().with("rankdir=LR");
+        for dir in dirs {
+            let size = self.get_directory_size(dir).unwrap();
+            graph.node(id(dir), &format!("{}/\n{}", name(dir), size));
+            for subdir in &self.immediate_directory_subdirectories[dir] {
+                graph.edge(id(dir), id(subdir));
+            }
+            for file in self
+                .immediate_directory_files
+                .get(dir)
+                .into_iter()
+                .flatten()
+            {
+                let size = self.file_sizes[file];
+                graph.node_with(file, &format!("{}\n{}", name(file), size), "shape=box");
+                graph.edge(id(dir), id(file));
+            }
+        }
+        graph
+    }
+
+    pub fn get_directory_size(&self, directory: &str) -> Option<usize> {
         if self
             .immediate_directory_subdirectories
             .contains_key(directory)
@@ -155,72 +260,18 @@ impl Default for FileTree {
 }
 
 impl Problem for Day<7> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+    type Input = FileTree;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        let mut tree = FileTree::new();
-        let mut current_path = "".to_string();
-
-        for line in reader.lines() {
-            let line = match line {
-                Ok(line) => line,
-                Err(_) => panic!(),
-            };
-
-            let args: Vec<&str> = line.split_whitespace().collect();
-
-            match args[0] {
-                "$" => match args[1] {
-                    "cd" => match args[2] {
-                        "/" => {
-                            current_path = "".to_string();
-                        }
-                        ".." => {
-                            let a = current_path.clone().rfind('/').unwrap();
-                            current_path = current_path[..a].to_string();
-                        }
-                        dirname => {
-                            let new_path = format!("{}/{}", current_path, dirname);
-                            assert!(tree
-                                .immediate_directory_subdirectories
-                                .get(&current_path)
-                                .unwrap()
-                                .contains(&new_path));
-                            current_path = new_path;
-                        }
-                    },
-                    "ls" => (),
-                    _ => panic!(),
-                },
-                "dir" => {
-                    let dirname = args[1];
-                    let dirpath = format!("{}/{}", current_path, dirname);
-                    tree.immediate_directory_subdirectories
-                        .entry(current_path.clone())
-                        .or_default()
-                        .push(dirpath.clone());
-                    tree.immediate_directory_subdirectories
-                        .entry(dirpath.clone())
-                        .or_default();
-                }
-                _ => {
-                    let filesize = args[0].parse::<usize>().unwrap();
-                    let filename = args[1];
-                    let filepath = format!("{}/{}", current_path, filename);
-
-                    tree.immediate_directory_files
-                        .entry(current_path.clone())
-                        .or_default()
-                        .push(filepath.clone());
-
-                    tree.file_sizes.insert(filepath, filesize);
-                }
-            }
-        }
+        FileTree::from_buffer(reader)
+    }
 
-        // writeln!("{}", tree);
+    fn part1(tree: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        trace!("{}", tree);
+        dot::export(config, || tree.draw())?;
 
         let sum: usize = tree
             .immediate_directory_subdirectories
@@ -228,13 +279,11 @@ impl Problem for Day<7> {
             .map(|dir| tree.get_directory_size(dir).unwrap_or(0))
             .filter(|&x| x <= 100000)
             .sum();
-        writeln!(
-            writer,
-            "Sum of directory sizes of size at most 100k: {}",
-            sum
-        )
-        .unwrap();
+        Answer::try_from(sum)
+    }
 
+    fn part2(tree: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        dot::export(config, || tree.draw())?;
         let used_memory = tree.get_directory_size("").unwrap();
         let total_memory = 70000000;
         let memory_needed = 30000000;
@@ -247,11 +296,6 @@ impl Problem for Day<7> {
             .filter(|&x| x >= need_to_free)
             .min()
             .unwrap();
-        writeln!(
-            writer,
-            "Size of smallest dir to free enough space: {}",
-            smallest_such_dir
-        )
-        .unwrap();
+        Answer::try_from(smallest_such_dir)
     }
 }
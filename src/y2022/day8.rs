@@ -6,7 +6,11 @@ use std::{
     io::BufReader,
 };
 
-use crate::{Day, Problem};
+use crate::{
+    error::ParseError,
+    image::{self, Image, Rgb},
+    trace, Answer, Config, Day, Error, Problem,
+};
 
 fn visible_from_start<I>(array: I) -> HashSet<usize>
 where
@@ -77,7 +81,7 @@ where
     result
 }
 
-struct Array2D {
+pub struct Array2D {
     width: usize,
     height: usize,
 
@@ -108,16 +112,24 @@ impl Array2D {
         }
     }
 
-    fn from_buffer<T>(reader: BufReader<T>) -> Result<Self, ()>
+    fn from_buffer<T>(reader: BufReader<T>) -> Result<Self, Error>
     where
         T: std::io::Read,
     {
         let mut array = Self::new();
         for (line_index, line) in reader.lines().enumerate() {
-            let line = match line {
-                Ok(line) => line,
-                Err(_) => return Err(()),
-            };
+            let line = line?;
+
+            if let Some(i) = line.find(|c: char| !c.is_ascii_digit()) {
+                return Err(
+                    ParseError::at(line_index + 1, &line, &line[i..], "a tree height").into(),
+                );
+            }
+            if line_index > 0 && line.len() != array.width {
+                let end = &line[line.len().min(array.width)..];
+                let expected = format!("a row of {} trees", array.width);
+                return Err(ParseError::at(line_index + 1, &line, end, expected).into());
+            }
 
             array.width = std::cmp::max(array.width, line.len());
             array.height = line_index + 1;
@@ -132,6 +144,22 @@ impl Array2D {
         Ok(array)
     }
 
+    /// Taller trees in lighter green, the ones visible from outside the grid tinted yellow.
+    fn draw(&self, visible: &HashSet<(usize, usize)>) -> Image {
+        let mut image = Image::new(self.width, self.height, Rgb::BLACK);
+        for (&y, row) in self.rows.iter() {
+            for (x, tree) in row.iter().enumerate() {
+                let height = tree.to_digit(10).unwrap_or(0) as f32 / 9.0;
+                let mut color = Rgb(10, 40, 10).mix(Rgb(120, 230, 120), height);
+                if visible.contains(&(x, y)) {
+                    color = color.mix(Rgb(250, 220, 60), 0.6);
+                }
+                image.set(x, y, color);
+            }
+        }
+        image
+    }
+
     fn scenic_score(&self, x: usize, y: usize) -> usize {
         let row = self.rows.get(&y).unwrap();
         let col = self.columns.get(&x).unwrap();
@@ -147,14 +175,17 @@ impl Array2D {
 }
 
 impl Problem for Day<8> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+    type Input = Array2D;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        let mut all_visible_trees = HashSet::new();
+        Array2D::from_buffer(reader)
+    }
 
-        let array = Array2D::from_buffer(reader).unwrap();
+    fn part1(array: &Self::Input, config: &Config) -> Result<Answer, Error> {
+        let mut all_visible_trees = HashSet::new();
 
         for (col_id, row) in array.rows.iter() {
             let visible1d = visible_from_either_end(row);
@@ -168,10 +199,13 @@ impl Problem for Day<8> {
             all_visible_trees.extend(visible2d);
         }
 
-        // writeln!("{}", array);
+        trace!("{}", array);
+        image::export(config, || array.draw(&all_visible_trees))?;
 
-        writeln!(writer, "Visible trees: {}", all_visible_trees.len()).unwrap();
+        Answer::try_from(all_visible_trees.len())
+    }
 
+    fn part2(array: &Self::Input, _config: &Config) -> Result<Answer, Error> {
         let scenic_scores = (0..array.height)
             .flat_map(|y| {
                 (0..array.width)
@@ -181,14 +215,14 @@ impl Problem for Day<8> {
             .filter(|(_, _, score)| *score != 0)
             .collect::<Vec<(usize, usize, usize)>>();
 
-        // writeln!("{:?}", scenic_scores);
+        trace!("{:?}", scenic_scores);
 
         let scenic = scenic_scores
             .iter()
             .max_by(|(_, _, a), (_, _, b)| a.cmp(b))
-            .unwrap();
-        let (x, y, score) = scenic;
+            .ok_or_else(|| Error::NoSolution("no tree has trees on every side".into()))?;
+        let (_x, _y, score) = *scenic;
 
-        writeln!(writer, "Max scenic score: {}, at ({},{})", score, x, y).unwrap();
+        Answer::try_from(score)
     }
 }
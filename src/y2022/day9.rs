@@ -1,12 +1,14 @@
 use std::{
-    cmp::max,
     collections::HashSet,
     io::prelude::*,
     io::BufReader,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
 
-use crate::{Day, Problem};
+use crate::{
+    error::{parse_field, ParseError},
+    Answer, Config, Day, Error, Problem,
+};
 
 // #[derive(AddAssign, SubAssign)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -85,7 +87,7 @@ struct RopeLink<T> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum MovementDir {
+pub enum MovementDir {
     None,
     R,
     U,
@@ -110,13 +112,6 @@ impl RopeLink<i32> {
         }
     }
 
-    fn get_nth(&self, n: usize) -> &Self {
-        match n {
-            0 => self,
-            _ => self.tail.as_ref().unwrap().get_nth(n - 1),
-        }
-    }
-
     fn get_last(&self) -> &Self {
         match self.tail.as_ref() {
             Some(tail) => tail.get_last(),
@@ -190,41 +185,55 @@ impl RopeLink<i32> {
     }
 }
 
+/// Pulls a rope with `tail_length` knots behind the head and counts the places its last knot visited.
+fn pull_rope(moves: &[(MovementDir, usize)], tail_length: usize) -> usize {
+    let mut rope = RopeLink::<i32>::with_tail_length(tail_length);
+    for &(dir, count) in moves {
+        rope.move_n_times(dir, count);
+    }
+    rope.get_last().trail.len()
+}
+
 impl Problem for Day<9> {
-    fn solve_buffer<T, W>(reader: BufReader<T>, writer: &mut W)
+    type Input = Vec<(MovementDir, usize)>;
+
+    fn parse<T>(reader: BufReader<T>) -> Result<Self::Input, Error>
     where
         T: std::io::Read,
-        W: std::io::Write,
     {
-        let mut result = 0;
-
-        let mut rope = RopeLink::<i32>::with_tail_length(9);
-
-        for line in reader.lines().map(|x| x.unwrap()) {
-            let args = line.split_whitespace().collect::<Vec<&str>>();
-            let count = args[1].parse().unwrap();
-            match args[0] {
-                "R" => rope.move_n_times(MovementDir::R, count),
-                "U" => rope.move_n_times(MovementDir::U, count),
-                "L" => rope.move_n_times(MovementDir::L, count),
-                "D" => rope.move_n_times(MovementDir::D, count),
-                _ => panic!(),
-            }
-
-            result = max(result, line.len());
+        let mut moves = vec![];
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let (dir, count) = match line.split_whitespace().collect::<Vec<&str>>()[..] {
+                [dir, count] => (dir, count),
+                _ => {
+                    return Err(ParseError::at(index + 1, &line, &line, "a move like 'R 4'").into())
+                }
+            };
+            let count = parse_field(index + 1, &line, count, "a step count")?;
+            let dir = match dir {
+                "R" => MovementDir::R,
+                "U" => MovementDir::U,
+                "L" => MovementDir::L,
+                "D" => MovementDir::D,
+                _ => {
+                    return Err(
+                        ParseError::at(index + 1, &line, dir, "'R', 'U', 'L' or 'D'").into(),
+                    )
+                }
+            };
+            moves.push((dir, count));
         }
 
-        writeln!(
-            writer,
-            "First child touched grass at {} places",
-            rope.get_nth(1).trail.len()
-        )
-        .unwrap();
-        writeln!(
-            writer,
-            "Last child at {} places",
-            rope.get_last().trail.len()
-        )
-        .unwrap();
+        Ok(moves)
+    }
+
+    fn part1(moves: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(pull_rope(moves, 1))
+    }
+
+    fn part2(moves: &Self::Input, _config: &Config) -> Result<Answer, Error> {
+        Answer::try_from(pull_rope(moves, 9))
     }
 }
@@ -0,0 +1,105 @@
+use crate::{differential::Pair, generate::Generator, registry::Solver, Day};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day16_part1;
+pub mod day16_part2;
+pub mod day17;
+pub mod day17_optimized;
+pub mod day17_pretty;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub const YEAR: u32 = 2022;
+
+#[rustfmt::skip]
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<Day<1>>(YEAR, 1, 1, "Calorie Counting"),
+    Solver::new::<Day<1>>(YEAR, 1, 2, "Calorie Counting"),
+    Solver::new::<Day<2>>(YEAR, 2, 1, "Rock Paper Scissors"),
+    Solver::new::<Day<2>>(YEAR, 2, 2, "Rock Paper Scissors"),
+    Solver::new::<Day<3>>(YEAR, 3, 1, "Rucksack Reorganization"),
+    Solver::new::<Day<3>>(YEAR, 3, 2, "Rucksack Reorganization"),
+    Solver::new::<Day<4>>(YEAR, 4, 1, "Camp Cleanup"),
+    Solver::new::<Day<4>>(YEAR, 4, 2, "Camp Cleanup"),
+    Solver::new::<Day<5>>(YEAR, 5, 1, "Supply Stacks"),
+    Solver::new::<Day<5>>(YEAR, 5, 2, "Supply Stacks"),
+    Solver::new::<Day<6>>(YEAR, 6, 1, "Tuning Trouble"),
+    Solver::new::<Day<6>>(YEAR, 6, 2, "Tuning Trouble"),
+    Solver::new::<Day<7>>(YEAR, 7, 1, "No Space Left On Device"),
+    Solver::new::<Day<7>>(YEAR, 7, 2, "No Space Left On Device"),
+    Solver::new::<Day<8>>(YEAR, 8, 1, "Treetop Tree House"),
+    Solver::new::<Day<8>>(YEAR, 8, 2, "Treetop Tree House"),
+    Solver::new::<Day<9>>(YEAR, 9, 1, "Rope Bridge"),
+    Solver::new::<Day<9>>(YEAR, 9, 2, "Rope Bridge"),
+    Solver::new::<Day<10>>(YEAR, 10, 1, "Cathode-Ray Tube"),
+    Solver::new::<Day<10>>(YEAR, 10, 2, "Cathode-Ray Tube"),
+    Solver::new::<Day<11>>(YEAR, 11, 1, "Monkey in the Middle"),
+    Solver::new::<Day<11>>(YEAR, 11, 2, "Monkey in the Middle"),
+    Solver::new::<Day<12>>(YEAR, 12, 1, "Hill Climbing Algorithm"),
+    Solver::new::<Day<12>>(YEAR, 12, 2, "Hill Climbing Algorithm"),
+    Solver::new::<Day<13>>(YEAR, 13, 1, "Distress Signal"),
+    Solver::new::<Day<13>>(YEAR, 13, 2, "Distress Signal"),
+    Solver::new::<Day<14>>(YEAR, 14, 1, "Regolith Reservoir"),
+    Solver::new::<Day<14>>(YEAR, 14, 2, "Regolith Reservoir"),
+    Solver::new::<Day<15>>(YEAR, 15, 1, "Beacon Exclusion Zone"),
+    Solver::new::<Day<15>>(YEAR, 15, 2, "Beacon Exclusion Zone"),
+    Solver::new::<Day<16>>(YEAR, 16, 1, "Proboscidea Volcanium"),
+    Solver::new::<Day<16>>(YEAR, 16, 2, "Proboscidea Volcanium"),
+    Solver::new::<Day<17>>(YEAR, 17, 1, "Pyroclastic Flow"),
+    Solver::new::<Day<17>>(YEAR, 17, 2, "Pyroclastic Flow"),
+    Solver::new::<Day<18>>(YEAR, 18, 1, "Boiling Boulders"),
+    Solver::new::<Day<18>>(YEAR, 18, 2, "Boiling Boulders"),
+    Solver::new::<Day<19>>(YEAR, 19, 1, "Not Enough Minerals"),
+    Solver::new::<Day<19>>(YEAR, 19, 2, "Not Enough Minerals"),
+    Solver::new::<Day<20>>(YEAR, 20, 1, "Grove Positioning System"),
+    Solver::new::<Day<20>>(YEAR, 20, 2, "Grove Positioning System"),
+    Solver::new::<Day<21>>(YEAR, 21, 1, "Monkey Math"),
+    Solver::new::<Day<21>>(YEAR, 21, 2, "Monkey Math"),
+    Solver::new::<Day<22>>(YEAR, 22, 1, "Monkey Map"),
+    Solver::new::<Day<22>>(YEAR, 22, 2, "Monkey Map"),
+    Solver::new::<Day<23>>(YEAR, 23, 1, "Unstable Diffusion"),
+    Solver::new::<Day<23>>(YEAR, 23, 2, "Unstable Diffusion"),
+    Solver::new::<Day<24>>(YEAR, 24, 1, "Blizzard Basin"),
+];
+
+#[rustfmt::skip]
+pub const GENERATORS: &[Generator] = &[
+    Generator::new(YEAR, 4, "section range pairs", 1000, day4::generate),
+    Generator::new(YEAR, 12, "squares across the heightmap", 160, day12::generate),
+    Generator::new(YEAR, 15, "sensors", 30, day15::generate),
+    Generator::new(YEAR, 16, "valves", 50, day16::generate),
+    Generator::new(YEAR, 17, "jets", 10000, day17::generate),
+    Generator::new(YEAR, 19, "blueprints", 30, day19::generate),
+    Generator::new(YEAR, 22, "tiles along a cube edge", 50, day22::generate),
+    Generator::new(YEAR, 24, "tiles across the valley", 120, day24::generate),
+];
+
+#[rustfmt::skip]
+pub const PAIRS: &[Pair] = &[
+    Pair::new::<Day<4>, day4::Day4Plain>(YEAR, 4, 1, ["lk_math", "plain"]),
+    Pair::new::<Day<4>, day4::Day4Plain>(YEAR, 4, 2, ["lk_math", "plain"]),
+    Pair::new::<Day<12>, day12::Day12Downhill>(YEAR, 12, 1, ["uphill", "downhill"]),
+    Pair::new::<Day<17>, day17_optimized::Day17Optimized<2022>>(YEAR, 17, 1, ["pretty", "optimized"]),
+    // Enough rocks for the tower to go round its cycle many times, which part 2 skips over.
+    Pair::new::<day17_optimized::Day17Optimized<20000>, day17_optimized::Day17Plain<20000>>(YEAR, 17, 1, ["skipping", "plain"]),
+];